@@ -0,0 +1,44 @@
+//! Embeds the fixtures of `examples/<year>/dayNN/` in the library, so that
+//! `--example` runs without the source tree, see `Fixture::embedded`.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// `.in` files of `dir` and its subdirectories.
+fn inputs(dir: &Path, found: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            inputs(&path, found);
+        } else if path.extension().is_some_and(|e| e == "in") {
+            found.push(path);
+        }
+    }
+}
+
+fn main() {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("examples");
+    println!("cargo::rerun-if-changed={}", root.display());
+
+    let mut found = vec![];
+    inputs(&root, &mut found);
+    found.sort();
+    let mut table = String::from("&[\n");
+    for input in found {
+        let relative = input.strip_prefix(&root).unwrap().to_string_lossy();
+        let out = input.with_extension("out");
+        let expected = match out.is_file() {
+            true => format!("Some(include_str!({out:?}))"),
+            false => "None".to_string(),
+        };
+        table += &format!("    ({relative:?}, include_str!({input:?}), {expected}),\n");
+    }
+    table += "]\n";
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("fixtures.rs"), table).unwrap();
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+
+use num_bigint::BigInt;
+use serde::{Serialize, Serializer};
+
+/// Answer to a part of a puzzle.
+///
+/// Integers of any primitive type convert losslessly, so a day can return
+/// its `u64` or `usize` result as is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    UInt(u128),
+    Big(BigInt),
+    Text(String),
+}
+
+macro_rules! from_int {
+    ($variant:ident: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+from_int!(Int: i8, i16, i32, i64, i128);
+from_int!(UInt: u8, u16, u32, u64, u128);
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Int(value as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::UInt(value as u128)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl Answer {
+    /// Answer displayed as `s`, as an integer if it is one.
+    pub fn parse(s: &str) -> Answer {
+        if let Ok(n) = s.parse() {
+            Answer::Int(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::UInt(n)
+        } else if let Ok(n) = s.parse() {
+            Answer::Big(n)
+        } else {
+            Answer::Text(s.to_string())
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::UInt(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+/// Numbers fitting in 64 bits are serialized as such, anything else as a
+/// string so that JSON readers don't lose precision.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => match i64::try_from(*n) {
+                Ok(n) => serializer.serialize_i64(n),
+                Err(_) => serializer.collect_str(n),
+            },
+            Answer::UInt(n) => match u64::try_from(*n) {
+                Ok(n) => serializer.serialize_u64(n),
+                Err(_) => serializer.collect_str(n),
+            },
+            answer => serializer.collect_str(answer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::answer::*;
+
+    #[test_log::test]
+    fn test_display() {
+        assert!(Answer::from(-3_i32).to_string() == "-3");
+        assert!(Answer::from(u64::MAX).to_string() == "18446744073709551615");
+        assert!(
+            Answer::from(BigInt::from(u128::MAX) * 2).to_string()
+                == "680564733841876926926749214863536422910"
+        );
+        assert!(Answer::from("abc").to_string() == "abc");
+    }
+
+    #[test_log::test]
+    fn test_serialize() {
+        assert!(json!(Answer::from(42_usize)) == json!(42));
+        assert!(json!(Answer::from(u64::MAX)) == json!(u64::MAX));
+        assert!(json!(Answer::from(i128::MIN)) == json!(i128::MIN.to_string()));
+        assert!(json!(Answer::from("abc")) == json!("abc"));
+    }
+}
@@ -0,0 +1,155 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use strum_macros::Display;
+
+use crate::{Day, Year};
+
+/// Known-correct answers, read from a TOML file such as:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1055
+/// part2 = 6386
+///
+/// [2024.day01]
+/// part1 = 2264607
+/// ```
+///
+/// Tables without a year are for the first year.
+#[derive(Clone, Debug, Default)]
+pub struct Answers(BTreeMap<String, DayAnswers>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Table {
+    Day(DayAnswers),
+    Year(BTreeMap<String, DayAnswers>),
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+}
+
+/// Result of comparing an answer against the expected one.
+#[derive(Clone, Debug, Display, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read answers from {}", path.display()))?;
+        Self::parse(&content).with_context(|| format!("invalid answers in {}", path.display()))
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let tables: BTreeMap<String, Table> = toml::from_str(content)?;
+        let mut answers = BTreeMap::new();
+        for (name, table) in tables {
+            match table {
+                Table::Day(day) => {
+                    answers.insert(name, day);
+                }
+                Table::Year(days) => {
+                    let year: Year = name.parse()?;
+                    for (name, day) in days {
+                        answers.insert(Self::key(year, &name), day);
+                    }
+                }
+            }
+        }
+        Ok(Answers(answers))
+    }
+
+    /// Key of the answers of day `name` of `year`, without a year for the
+    /// first one.
+    fn key(year: Year, name: &str) -> String {
+        match year == Year::first() {
+            true => name.to_string(),
+            false => format!("{year}.{name}"),
+        }
+    }
+
+    fn key_of(day: &Day) -> String {
+        Self::key(day.year(), &day.to_string().to_lowercase())
+    }
+
+    pub fn set(&mut self, day: &Day, part: u8, answer: impl ToString) {
+        let answers = self.0.entry(Self::key_of(day)).or_default();
+        let value = Some(toml::Value::String(answer.to_string()));
+        match part {
+            1 => answers.part1 = value,
+            _ => answers.part2 = value,
+        }
+    }
+
+    pub fn expected(&self, day: &Day, part: u8) -> Option<String> {
+        let answers = self.0.get(&Self::key_of(day))?;
+        let value = match part {
+            1 => answers.part1.as_ref(),
+            _ => answers.part2.as_ref(),
+        }?;
+        match value {
+            toml::Value::String(s) => Some(s.clone()),
+            value => Some(value.to_string()),
+        }
+    }
+
+    /// Compare `answer` to the expected answer of `part` of `day`. Failing
+    /// to produce an answer when one is expected is a failure.
+    pub fn check(&self, day: &Day, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.expected(day, part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Pass,
+            (Some(expected), _) => Verdict::Fail { expected },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    static ANSWERS: &str = r#"
+[day01]
+part1 = 3
+part2 = "6"
+
+[2025.day12]
+part1 = 2
+"#;
+
+    #[test_log::test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let day = |n| Year::Y2025.day(n).unwrap();
+        assert!(answers.check(&day(1), 1, Some("3")) == Verdict::Pass);
+        assert!(answers.check(&day(1), 2, Some("6")) == Verdict::Pass);
+        assert!(
+            answers.check(&day(1), 1, Some("4"))
+                == Verdict::Fail {
+                    expected: "3".into()
+                }
+        );
+        assert!(
+            answers.check(&day(12), 1, None)
+                == Verdict::Fail {
+                    expected: "2".into()
+                }
+        );
+        assert!(answers.check(&day(12), 2, None) == Verdict::Unknown);
+        assert!(answers.check(&day(5), 1, Some("3")) == Verdict::Unknown);
+        assert!(Answers::parse("[day01]\npart3 = 1").is_err());
+        assert!(Answers::parse("[1999.day01]\npart1 = 1").is_err());
+    }
+}
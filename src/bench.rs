@@ -0,0 +1,164 @@
+use std::time::{Duration, Instant};
+
+use crate::{AocRun, Parsed, RunOptions, panics, selection::DaySelection, timing::format_duration};
+use anyhow::{Result, bail};
+use nanospinner::Spinner;
+
+/// Summary of the durations measured over several runs of a part.
+#[derive(Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // nearest-rank percentile
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+
+        let mean = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Some(Stats {
+            runs: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Parse the input of each of `days` and run their selected parts `warmup`
+/// times, then `runs` more times while measuring them, and print their
+/// statistics. Parts are all solved from the same parsed input.
+pub fn bench(days: &DaySelection, options: &RunOptions, runs: usize, warmup: usize) -> Result<()> {
+    if runs == 0 {
+        bail!("at least one run is needed");
+    }
+    if options.input.is_some() && days.days().len() > 1 {
+        bail!("an input can only be given for a single day")
+    }
+
+    for &day in days.days() {
+        let module = options.module(&day)?;
+        println!("{day}:");
+        let spinner = Spinner::new(" Benchmarking parsing").start();
+        let content = options.input_for(&day).read_to_string();
+        let parsed = match content.and_then(|c| bench_parse(module.as_ref(), &c, runs, warmup)) {
+            Ok((stats, parsed)) => {
+                spinner.success_with(format!(" Parse: {}", describe(&stats)));
+                parsed
+            }
+            Err(e) => {
+                spinner.fail_with(format!(" {}", e));
+                continue;
+            }
+        };
+        for part in (1..=2).filter(|&part| options.runs(&day, part)) {
+            let spinner = Spinner::new(format!(" Benchmarking part {part}")).start();
+            match bench_part(module.as_ref(), part, &parsed, runs, warmup) {
+                Ok(stats) => spinner.success_with(format!(" Part {}: {}", part, describe(&stats))),
+                Err(e) => spinner.fail_with(format!(" {}", e)),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn describe(stats: &Stats) -> String {
+    format!(
+        "min {}, median {}, mean {}, p95 {}, stddev {} ({} runs)",
+        format_duration(stats.min),
+        format_duration(stats.median),
+        format_duration(stats.mean),
+        format_duration(stats.p95),
+        format_duration(stats.stddev),
+        stats.runs
+    )
+}
+
+/// Measure `f`, returning the statistics and the last result.
+fn sample<T>(mut f: impl FnMut() -> Result<T>, runs: usize, warmup: usize) -> Result<(Stats, T)> {
+    for _ in 0..warmup {
+        f()?;
+    }
+    let mut samples = vec![];
+    let mut last = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let res = f()?;
+        samples.push(start.elapsed());
+        last = Some(res);
+    }
+    Ok((Stats::from_samples(&samples).unwrap(), last.unwrap()))
+}
+
+fn bench_parse(
+    module: &dyn AocRun,
+    content: &str,
+    runs: usize,
+    warmup: usize,
+) -> Result<(Stats, Parsed)> {
+    sample(
+        || panics::catch(|| module.parse(content)).map_err(anyhow::Error::msg)?,
+        runs,
+        warmup,
+    )
+}
+
+fn bench_part(
+    module: &dyn AocRun,
+    part: u8,
+    input: &Parsed,
+    runs: usize,
+    warmup: usize,
+) -> Result<Stats> {
+    let run = || panics::catch(|| module.solve(part, input)).map_err(anyhow::Error::msg)?;
+    Ok(sample(run, runs, warmup)?.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    #[test_log::test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 1, 3, 2, 5].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert!(stats.min == Duration::from_millis(1));
+        assert!(stats.median == Duration::from_millis(3));
+        assert!(stats.mean == Duration::from_millis(3));
+        assert!(stats.p95 == Duration::from_millis(5));
+        assert!((stats.stddev.as_secs_f64() - 2_f64.sqrt() / 1e3).abs() < 1e-9);
+    }
+
+    #[test_log::test]
+    fn test_stats_even() {
+        let samples: Vec<Duration> = [1, 2, 3, 4].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert!(stats.median == Duration::from_micros(2500));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+}
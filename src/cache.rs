@@ -0,0 +1,198 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anyhow::{Context, Result};
+use tracing::warn;
+
+use crate::{Answer, Day, RunOptions};
+
+/// Answers computed by earlier runs, stored on disk.
+///
+/// An answer is keyed by its day and part, a hash of the input and a
+/// fingerprint of the solver: the source of the day's module and its
+/// parameters. Changes to shared code such as `utils` aren't noticed, clear
+/// the cache after them.
+#[derive(Debug)]
+pub struct ResultCache {
+    path: PathBuf,
+    answers: Mutex<BTreeMap<String, String>>,
+}
+
+impl ResultCache {
+    /// Cache stored at `path`, empty if there is none or it is invalid.
+    pub fn load(path: &Path) -> Self {
+        let content = fs::read_to_string(path).unwrap_or_default();
+        let answers = match serde_json::from_str(&content) {
+            Ok(answers) => answers,
+            Err(e) if !content.is_empty() => {
+                warn!("ignoring invalid result cache in {}: {e}", path.display());
+                BTreeMap::new()
+            }
+            Err(_) => BTreeMap::new(),
+        };
+        ResultCache {
+            path: path.to_path_buf(),
+            answers: Mutex::new(answers),
+        }
+    }
+
+    /// Remove the cache at `path`. Returns whether there was one.
+    pub fn clear(path: &Path) -> Result<bool> {
+        match fs::remove_file(path) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e).with_context(|| format!("failed to remove {}", path.display())),
+        }
+    }
+
+    /// Key of the answers of `day` run on the input hashed as `hash` with
+    /// the parameters of `options`.
+    pub fn key(day: &Day, hash: &str, options: &RunOptions) -> String {
+        let mut fingerprint = Fnv::default();
+        fingerprint.write(day.entry().source.as_bytes());
+        for (key, value) in options.params.get(day).into_iter().flat_map(|p| p.iter()) {
+            fingerprint.write(format!("\0{key}={value}").as_bytes());
+        }
+        format!(
+            "{}/{}/{hash}/{:016x}",
+            day.year(),
+            day.to_string().to_lowercase(),
+            fingerprint.0
+        )
+    }
+
+    pub fn get(&self, key: &str, part: u8) -> Option<Answer> {
+        let answers = self.answers.lock().unwrap();
+        answers
+            .get(&format!("{key}/{part}"))
+            .map(|a| Answer::parse(a))
+    }
+
+    /// Store `answer` and save the cache. Failing to save it only warns.
+    pub fn put(&self, key: &str, part: u8, answer: &Answer) {
+        let mut answers = self.answers.lock().unwrap();
+        answers.insert(format!("{key}/{part}"), answer.to_string());
+        if let Err(e) = self.save(&answers) {
+            warn!("failed to save the result cache: {e:#}");
+        }
+    }
+
+    fn save(&self, answers: &BTreeMap<String, String>) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(answers)?)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+}
+
+/// Hash of the content of an input.
+pub fn hash(content: &str) -> String {
+    format!("{:016x}", Fnv::hash(content.as_bytes()))
+}
+
+/// 64-bit FNV-1a, stable across builds unlike the std hashers.
+struct Fnv(u64);
+
+impl Default for Fnv {
+    fn default() -> Self {
+        Fnv(0xcbf29ce484222325)
+    }
+}
+
+impl Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 ^ b as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn hash(bytes: &[u8]) -> u64 {
+        let mut fnv = Fnv::default();
+        fnv.write(bytes);
+        fnv.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        InputSource, Year,
+        cache::*,
+        params::Params,
+        report::{Record, Reporter},
+        selection::DaySelection,
+    };
+
+    #[derive(Default)]
+    struct Collect(Vec<Record>);
+
+    impl Reporter for Collect {
+        fn record(&mut self, record: Record) {
+            self.0.push(record);
+        }
+    }
+
+    #[test_log::test]
+    fn test_key() {
+        assert!(Fnv::hash(b"") == 0xcbf29ce484222325);
+        assert!(Fnv::hash(b"a") == 0xaf63dc4c8601ec8c);
+
+        assert!(hash("a") == "af63dc4c8601ec8c");
+
+        let day = Year::Y2025.day(8).unwrap();
+        let mut options = RunOptions::default();
+        let key = ResultCache::key(&day, "0123", &options);
+        assert!(key.starts_with("2025/day08/0123/"));
+        let params: Params = [("connections", "10")].into_iter().collect();
+        options.params.insert(day, params);
+        assert!(ResultCache::key(&day, "0123", &options) != key);
+    }
+
+    #[test_log::test]
+    fn test_get_put() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-{}.json", std::process::id()));
+        let cache = ResultCache::load(&path);
+        assert!(cache.get("2025/day01/0/0", 1).is_none());
+        cache.put("2025/day01/0/0", 1, &Answer::from(1055));
+        let cache = ResultCache::load(&path);
+        assert!(cache.get("2025/day01/0/0", 1) == Some(Answer::from(1055)));
+        assert!(cache.get("2025/day01/0/0", 2).is_none());
+        assert!(ResultCache::clear(&path).unwrap());
+        assert!(!ResultCache::clear(&path).unwrap());
+    }
+
+    #[test_log::test]
+    fn test_cached_run() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-run-{}.json", std::process::id()));
+        let days = DaySelection::parse(Year::Y2025, "1").unwrap();
+        let example = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/2025/day01/example.in");
+        let mut options = RunOptions {
+            input: Some(InputSource::File(example)),
+            cache: Some(Arc::new(ResultCache::load(&path))),
+            ..RunOptions::default()
+        };
+        let answers = |records: &[Record]| -> Vec<String> {
+            records
+                .iter()
+                .map(|r| r.answer.as_ref().unwrap().to_string())
+                .collect()
+        };
+
+        let mut solved = Collect::default();
+        crate::run(&days, &options, &mut solved).unwrap();
+        assert!(solved.0.iter().all(|r| !r.cached));
+        options.cached = true;
+        let mut cached = Collect::default();
+        crate::run(&days, &options, &mut cached).unwrap();
+        assert!(cached.0.len() == 2 && cached.0.iter().all(|r| r.cached));
+        assert!(answers(&cached.0) == answers(&solved.0));
+        ResultCache::clear(&path).unwrap();
+    }
+}
@@ -0,0 +1,291 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::Year;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Time to wait after a wrong answer when the server doesn't tell.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// Talks to the Advent of Code server on behalf of the owner of a session
+/// token, the value of the `session` cookie of a logged in browser.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+/// How the server took a submitted answer.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Right,
+    /// Wrong answer, along with the server's hint such as "too high".
+    Wrong {
+        hint: Option<String>,
+    },
+    /// Submitted too soon after a previous answer.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part is already solved, or its level isn't unlocked.
+    WrongLevel,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Right => write!(f, "right answer"),
+            Outcome::Wrong { hint: None } => write!(f, "wrong answer"),
+            Outcome::Wrong { hint: Some(hint) } => write!(f, "wrong answer, {hint}"),
+            Outcome::TooSoon { wait } => write!(f, "too soon, wait {}s", wait.as_secs()),
+            Outcome::WrongLevel => write!(f, "not the right level, already solved?"),
+        }
+    }
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(concat!(
+                env!("CARGO_PKG_NAME"),
+                "/",
+                env!("CARGO_PKG_VERSION")
+            ))
+            .build()
+            .into();
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent,
+        }
+    }
+
+    fn url(&self, year: Year, day: u8, page: &str) -> String {
+        format!("{}/{year}/day/{day}/{page}", self.base_url)
+    }
+
+    /// Puzzle input of `day` of `year`.
+    pub fn input(&self, year: Year, day: u8) -> Result<String> {
+        let url = self.url(year, day, "input");
+        info!(%url, "fetching input");
+        let res = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call();
+        self.response(res, year, day)
+    }
+
+    /// Submit `answer` to `part` of `day` of `year`.
+    pub fn answer(&self, year: Year, day: u8, part: u8, answer: &str) -> Result<Outcome> {
+        let url = self.url(year, day, "answer");
+        info!(%url, part, answer, "submitting answer");
+        let res = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)]);
+        parse_outcome(&self.response(res, year, day)?)
+    }
+
+    fn response(
+        &self,
+        res: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+        year: Year,
+        day: u8,
+    ) -> Result<String> {
+        match res {
+            Ok(mut res) => Ok(res.body_mut().read_to_string()?),
+            Err(ureq::Error::StatusCode(404)) => bail!("{year} day {day} is not unlocked yet"),
+            Err(ureq::Error::StatusCode(400 | 500)) => {
+                bail!("the server rejected the request, is the session token still valid?")
+            }
+            Err(e) => Err(anyhow!(e).context(format!("failed to reach {}", self.base_url))),
+        }
+    }
+}
+
+/// Outcome told by the page answering a submission.
+pub fn parse_outcome(page: &str) -> Result<Outcome> {
+    let text = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+    if text.contains("That's the right answer") {
+        Ok(Outcome::Right)
+    } else if text.contains("That's not the right answer") {
+        let hint = ["too high", "too low"]
+            .into_iter()
+            .find(|hint| text.contains(hint))
+            .map(String::from);
+        Ok(Outcome::Wrong { hint })
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Outcome::TooSoon {
+            wait: parse_wait(text).unwrap_or(WRONG_ANSWER_WAIT),
+        })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::WrongLevel)
+    } else {
+        Err(anyhow!("unexpected answer from the server"))
+            .context(text.chars().take(500).collect::<String>())
+    }
+}
+
+/// Wait in "You have 1m 23s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut secs = 0;
+    for token in text[start..end].split_whitespace() {
+        let (n, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        secs += n.parse::<u64>().ok()?
+            * match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Input of `day` in `dir`, downloaded unless it's already there.
+/// Returns its path and whether it was downloaded.
+pub fn fetch(
+    client: &Client,
+    year: Year,
+    day: u8,
+    dir: &Path,
+    force: bool,
+) -> Result<(PathBuf, bool)> {
+    let path = dir.join(format!("day{day:02}.txt"));
+    // scaffolded days come with an empty input
+    let cached = fs::metadata(&path).is_ok_and(|m| m.len() > 0);
+    if cached && !force {
+        return Ok((path, false));
+    }
+    let input = client.input(year, day)?;
+    fs::create_dir_all(dir)?;
+    fs::write(&path, input).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok((path, true))
+}
+
+/// Answers submitted so far and when the server accepts the next one, kept
+/// in a file between runs.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Submissions {
+    /// Seconds since the epoch before which not to submit.
+    not_before: u64,
+    /// Outcome of each answer, by `<year>/dayNN/<part>`.
+    answers: BTreeMap<String, BTreeMap<String, Outcome>>,
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl Submissions {
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut submissions: Submissions = match fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("invalid submissions in {}", path.display()))?,
+            Err(_) => Submissions::default(),
+        };
+        submissions.path = path.to_path_buf();
+        Ok(submissions)
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+
+    /// Submit `answer` to `part` of `day`, unless it was already: the
+    /// outcome it got then is returned instead. Refuses to submit while the
+    /// server would turn the answer down for being too soon.
+    pub fn submit(
+        &mut self,
+        client: &Client,
+        year: Year,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome> {
+        let key = format!("{year}/day{day:02}/{part}");
+        let answers = self.answers.entry(key).or_default();
+        if let Some(outcome) = answers.get(answer) {
+            return Ok(outcome.clone());
+        }
+        if let Some((answer, _)) = answers.iter().find(|(_, o)| **o == Outcome::Right) {
+            bail!("part {part} was already solved with {answer}");
+        }
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        if now < self.not_before {
+            bail!(
+                "too soon to submit another answer, wait {}s",
+                self.not_before - now
+            );
+        }
+
+        let outcome = client.answer(year, day, part, answer)?;
+        let wait = match &outcome {
+            Outcome::Wrong { .. } => WRONG_ANSWER_WAIT,
+            Outcome::TooSoon { wait } => *wait,
+            _ => Duration::ZERO,
+        };
+        self.not_before = now + wait.as_secs();
+        if !matches!(outcome, Outcome::TooSoon { .. }) {
+            answers.insert(answer.to_string(), outcome.clone());
+        }
+        self.save()?;
+        Ok(outcome)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::*;
+
+    fn page(text: &str) -> String {
+        format!("<html><main>\n<article><p>{text}</p></article>\n</main></html>")
+    }
+
+    #[test_log::test]
+    fn test_parse_outcome() {
+        let right = "That's the right answer!  You are one gold star closer.";
+        assert!(parse_outcome(&page(right)).unwrap() == Outcome::Right);
+        let wrong = "That's not the right answer; your answer is too low.  \
+                     Please wait one minute before trying again.";
+        let hint = Some("too low".to_string());
+        assert!(parse_outcome(&page(wrong)).unwrap() == Outcome::Wrong { hint });
+        let soon = "You gave an answer too recently; you have to wait after \
+                    submitting an answer before trying again.  You have 1m 23s left to wait.";
+        let wait = Duration::from_secs(83);
+        assert!(parse_outcome(&page(soon)).unwrap() == Outcome::TooSoon { wait });
+        let level = "You don't seem to be solving the right level.  Did you already complete it?";
+        assert!(parse_outcome(&page(level)).unwrap() == Outcome::WrongLevel);
+        assert!(parse_outcome(&page("Something else")).is_err());
+    }
+
+    #[test_log::test]
+    fn test_display() {
+        let wrong = Outcome::Wrong {
+            hint: Some("too high".into()),
+        };
+        assert!(wrong.to_string() == "wrong answer, too high");
+        let wait = Duration::from_secs(30);
+        assert!(Outcome::TooSoon { wait }.to_string() == "too soon, wait 30s");
+    }
+}
@@ -0,0 +1,229 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, anyhow};
+use serde::Deserialize;
+
+use crate::{Day, Year, client::BASE_URL, params::Params};
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Project settings read from `aoc.toml`, for example:
+///
+/// ```toml
+/// year = 2025
+/// input_dir = "input"
+/// profiles_dir = "inputs"
+/// format = "pretty"
+/// parts = "both"
+/// log = "info"
+/// answers = "answers.toml"
+/// cache_dir = ".aoc"
+/// base_url = "https://adventofcode.com"
+/// session = "53616c7465645f5f..."
+///
+/// [day08]
+/// connections = 1000
+///
+/// [2024.day08]
+/// size = 50
+/// ```
+///
+/// Tables without a year, such as `[day08]`, are for the first year.
+///
+/// Relative paths are resolved from the directory holding the file.
+/// Command line flags take precedence over all these settings.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Year run when none is given, the latest one by default.
+    pub year: Option<u16>,
+    /// Holds the inputs of each year, as `<input_dir>/<year>/dayNN.txt`.
+    pub input_dir: Option<PathBuf>,
+    /// Holds one input set per profile, as `<profiles_dir>/<profile>/<year>/dayNN.txt`.
+    pub profiles_dir: Option<PathBuf>,
+    pub format: Option<String>,
+    pub parts: Option<String>,
+    pub log: Option<String>,
+    pub answers: Option<PathBuf>,
+    /// Holds the files kept between runs: result cache, history and
+    /// answers submitted.
+    pub cache_dir: Option<PathBuf>,
+    pub base_url: Option<String>,
+    /// Session token to talk to the server, `AOC_SESSION` takes precedence.
+    pub session: Option<String>,
+    /// Per-day parameters, as `[dayNN]` and `[<year>.dayNN]` tables.
+    #[serde(flatten)]
+    days: BTreeMap<String, toml::Table>,
+    #[serde(skip)]
+    root: PathBuf,
+}
+
+impl Config {
+    /// Look for `aoc.toml` in the current directory and its parents.
+    pub fn find() -> Result<Option<Self>> {
+        let cwd = env::current_dir()?;
+        for dir in cwd.ancestors() {
+            let path = dir.join(CONFIG_FILE);
+            if path.is_file() {
+                return Self::load(&path).map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read config from {}", path.display()))?;
+        let mut config = Self::parse(&content)
+            .with_context(|| format!("invalid config in {}", path.display()))?;
+        config.root = path.parent().unwrap_or(Path::new(".")).to_path_buf();
+        Ok(config)
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let config: Config = toml::from_str(content)?;
+        config.year()?;
+        config.params()?;
+        Ok(config)
+    }
+
+    /// Resolve `path` from the directory of the config file.
+    pub fn resolve(&self, path: impl AsRef<Path>) -> PathBuf {
+        self.root.join(path)
+    }
+
+    pub fn input_dir(&self) -> PathBuf {
+        self.resolve(self.input_dir.as_deref().unwrap_or(Path::new("input")))
+    }
+
+    pub fn profiles_dir(&self) -> PathBuf {
+        self.resolve(self.profiles_dir.as_deref().unwrap_or(Path::new("inputs")))
+    }
+
+    pub fn answers(&self) -> PathBuf {
+        self.resolve(self.answers.as_deref().unwrap_or(Path::new("answers.toml")))
+    }
+
+    pub fn cache_dir(&self) -> PathBuf {
+        self.resolve(self.cache_dir.as_deref().unwrap_or(Path::new(".aoc")))
+    }
+
+    /// Result cache, in the cache directory.
+    pub fn results(&self) -> PathBuf {
+        self.cache_dir().join("results.json")
+    }
+
+    /// Log of the parts solved, in the cache directory.
+    pub fn history(&self) -> PathBuf {
+        self.cache_dir().join("history.jsonl")
+    }
+
+    /// Answers submitted to the server, in the cache directory.
+    pub fn submissions(&self) -> PathBuf {
+        self.cache_dir().join("submissions.json")
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(BASE_URL)
+    }
+
+    pub fn session(&self) -> Result<String> {
+        env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+            .ok_or_else(|| {
+                anyhow!("no session token, set AOC_SESSION or `session` in {CONFIG_FILE}")
+            })
+    }
+
+    pub fn year(&self) -> Result<Year> {
+        match self.year {
+            Some(year) => year.to_string().parse(),
+            None => Ok(Year::latest()),
+        }
+    }
+
+    /// Parameters of each day having a `[dayNN]` or `[<year>.dayNN]` table.
+    pub fn params(&self) -> Result<HashMap<Day, Params>> {
+        let mut params = HashMap::new();
+        for (name, table) in &self.days {
+            match name.parse::<u16>() {
+                Ok(_) => {
+                    let year: Year = name.parse()?;
+                    for (name, table) in table {
+                        let table = table
+                            .as_table()
+                            .ok_or_else(|| anyhow!("'{year}.{name}' is not a table"))?;
+                        params.insert(Self::day(year, name)?, Self::params_of(table));
+                    }
+                }
+                Err(_) => {
+                    params.insert(Self::day(Year::first(), name)?, Self::params_of(table));
+                }
+            }
+        }
+        Ok(params)
+    }
+
+    fn day(year: Year, name: &str) -> Result<Day> {
+        year.days()
+            .find(|d| d.to_string().to_lowercase() == *name)
+            .ok_or_else(|| anyhow!("unknown setting or day '{name}' of {year}"))
+    }
+
+    fn params_of(table: &toml::Table) -> Params {
+        table
+            .iter()
+            .map(|(key, value)| match value {
+                toml::Value::String(s) => (key.clone(), s.clone()),
+                value => (key.clone(), value.to_string()),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+
+    static CONFIG: &str = r#"
+input_dir = "inputs"
+format = "json"
+
+[day08]
+connections = 10
+
+[2025.day01]
+dial = 50
+"#;
+
+    #[test_log::test]
+    fn test_parse() {
+        let config = Config::parse(CONFIG).unwrap();
+        assert!(config.input_dir() == Path::new("inputs"));
+        assert!(config.answers() == Path::new("answers.toml"));
+        assert!(config.base_url() == BASE_URL);
+        assert!(config.format.as_deref() == Some("json"));
+        assert!(config.parts.is_none());
+        assert!(config.year().unwrap() == Year::latest());
+        let params = config.params().unwrap();
+        let day = |n| Year::Y2025.day(n).unwrap();
+        assert!(params[&day(8)].get::<i32>("connections").unwrap() == Some(10));
+        assert!(params[&day(1)].get::<i32>("dial").unwrap() == Some(50));
+    }
+
+    #[test_log::test]
+    fn test_parse_errors() {
+        assert!(Config::parse("[day99]\nx = 1").is_err());
+        assert!(Config::parse("input_dirs = \"x\"").is_err());
+        assert!(Config::parse("jobs = 4").is_err());
+        assert!(Config::parse("year = 1999").is_err());
+        assert!(Config::parse("[1999.day01]\nx = 1").is_err());
+        assert!(Config::parse("[2025.day99]\nx = 1").is_err());
+    }
+}
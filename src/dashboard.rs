@@ -0,0 +1,565 @@
+use std::{
+    collections::HashMap,
+    io::{self, IsTerminal},
+    sync::{Arc, Mutex, mpsc},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{Result, bail};
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event as TermEvent, KeyCode, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Cell, Clear, Paragraph, Row as TableRow, Table, TableState},
+};
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::{
+    Day, RunOptions, RunSummary,
+    answers::Verdict,
+    error::Failure,
+    report::{Record, Reporter},
+    selection::DaySelection,
+    timing::format_duration,
+};
+
+/// Lines kept in the log of a day, the oldest ones going first.
+const MAX_LOG_LINES: usize = 1000;
+
+/// Lines logged while running each day, kept for the dashboard rather than
+/// written over the screen it draws.
+#[derive(Clone, Debug, Default)]
+pub struct Logs(Arc<Mutex<LogState>>);
+
+#[derive(Debug, Default)]
+struct LogState {
+    /// Day being run, to which the lines logged go.
+    current: Option<Day>,
+    lines: HashMap<Day, Vec<String>>,
+}
+
+impl Logs {
+    /// Send the lines logged from now on to a fresh log of `day`.
+    fn start(&self, day: &Day) {
+        let mut state = self.0.lock().unwrap();
+        state.current = Some(*day);
+        state.lines.insert(*day, vec![]);
+    }
+
+    fn lines(&self, day: &Day) -> Vec<String> {
+        let state = self.0.lock().unwrap();
+        state.lines.get(day).cloned().unwrap_or_default()
+    }
+}
+
+/// Buffer of a logged event, added to the log of the current day once
+/// written.
+pub struct LogWriter {
+    logs: Logs,
+    buffer: Vec<u8>,
+}
+
+impl io::Write for LogWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for LogWriter {
+    fn drop(&mut self) {
+        let mut state = self.logs.0.lock().unwrap();
+        let Some(day) = state.current else {
+            return;
+        };
+        let lines = state.lines.entry(day).or_default();
+        let text = String::from_utf8_lossy(&self.buffer);
+        lines.extend(text.lines().map(String::from));
+        let excess = lines.len().saturating_sub(MAX_LOG_LINES);
+        lines.drain(..excess);
+    }
+}
+
+impl<'a> MakeWriter<'a> for Logs {
+    type Writer = LogWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LogWriter {
+            logs: self.clone(),
+            buffer: vec![],
+        }
+    }
+}
+
+/// Progress of the days run in the background.
+enum Event {
+    Started(Day, u8),
+    Skipped(Day, u8),
+    Record(Record),
+    /// The day couldn't be run at all.
+    Error(Day, String, Option<Failure>),
+}
+
+/// Forwards the progress of a run to the dashboard.
+struct Progress(mpsc::Sender<Event>);
+
+impl Reporter for Progress {
+    fn part_started(&mut self, day: &Day, part: u8) {
+        let _ = self.0.send(Event::Started(*day, part));
+    }
+    fn part_skipped(&mut self, day: &Day, part: u8) {
+        let _ = self.0.send(Event::Skipped(*day, part));
+    }
+    fn record(&mut self, record: Record) {
+        let _ = self.0.send(Event::Record(record));
+    }
+}
+
+/// Run the days of each job received, one after the other.
+fn work(
+    options: &RunOptions,
+    logs: &Logs,
+    jobs: mpsc::Receiver<Vec<Day>>,
+    events: mpsc::Sender<Event>,
+) {
+    for days in jobs {
+        for day in days {
+            logs.start(&day);
+            let mut progress = Progress(events.clone());
+            let res = options.module(&day).and_then(|module| {
+                module.run(&day, &options.input_for(&day), options, &mut progress)
+            });
+            if let Err(e) = res {
+                let _ = events.send(Event::Error(day, format!("{e:#}"), Failure::find(&e)));
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Status {
+    #[default]
+    NotRun,
+    Running,
+    Ok,
+    Failed,
+    Mismatch,
+    Skipped,
+}
+
+impl Status {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Status::NotRun => "·",
+            Status::Running => "…",
+            Status::Ok => "✔",
+            Status::Failed => "✖",
+            Status::Mismatch => "≠",
+            Status::Skipped => "-",
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Status::NotRun | Status::Skipped => Color::DarkGray,
+            Status::Running => Color::Cyan,
+            Status::Ok => Color::Green,
+            Status::Failed => Color::Red,
+            Status::Mismatch => Color::Yellow,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Part {
+    status: Status,
+    answer: Option<String>,
+    /// Runtime, unknown for cached answers.
+    time: Option<Duration>,
+    error: Option<String>,
+    failure: Option<Failure>,
+}
+
+struct Row {
+    day: Day,
+    parts: [Part; 2],
+}
+
+impl Row {
+    fn time(&self) -> Duration {
+        self.parts.iter().filter_map(|p| p.time).sum()
+    }
+
+    /// Error of the last part that failed.
+    fn error(&self) -> Option<String> {
+        (1u8..=2)
+            .zip(&self.parts)
+            .rev()
+            .find_map(|(n, p)| p.error.as_ref().map(|e| format!("part {n}: {e}")))
+    }
+}
+
+/// Full screen view of the status of each part of the selected days,
+/// updated as they run.
+pub struct Dashboard {
+    rows: Vec<Row>,
+    selected: usize,
+    show_log: bool,
+    logs: Logs,
+}
+
+impl Dashboard {
+    fn new(days: &DaySelection, logs: Logs) -> Self {
+        let rows = days
+            .days()
+            .iter()
+            .map(|day| Row {
+                day: *day,
+                parts: Default::default(),
+            })
+            .collect();
+        Dashboard {
+            rows,
+            selected: 0,
+            show_log: false,
+            logs,
+        }
+    }
+
+    fn part(&mut self, year: u16, day: u8, part: u8) -> Option<&mut Part> {
+        self.rows
+            .iter_mut()
+            .find(|row| row.day.year() as u16 == year && row.day.number() == day)
+            .and_then(|row| row.parts.get_mut(part as usize - 1))
+    }
+
+    fn apply(&mut self, event: Event) {
+        match event {
+            Event::Started(day, part) | Event::Skipped(day, part) => {
+                let running = matches!(event, Event::Started(..));
+                if let Some(state) = self.part(day.year() as u16, day.number(), part) {
+                    *state = Part {
+                        status: match running {
+                            true => Status::Running,
+                            false => Status::Skipped,
+                        },
+                        ..Part::default()
+                    };
+                }
+            }
+            Event::Record(record) => {
+                let error = match (&record.error, &record.verdict) {
+                    (Some(error), _) => Some(error.clone()),
+                    (None, Some(Verdict::Fail { expected })) => {
+                        Some(format!("expected {expected}"))
+                    }
+                    _ => None,
+                };
+                if let Some(state) = self.part(record.year, record.day, record.part) {
+                    *state = Part {
+                        status: match record.failure {
+                            None => Status::Ok,
+                            Some(Failure::Mismatch) => Status::Mismatch,
+                            Some(_) => Status::Failed,
+                        },
+                        answer: record.answer.as_ref().map(|a| a.to_string()),
+                        time: (!record.cached).then(|| record.timings.total()),
+                        error,
+                        failure: record.failure,
+                    };
+                }
+            }
+            Event::Error(day, error, failure) => {
+                for part in 1..=2 {
+                    if let Some(state) = self.part(day.year() as u16, day.number(), part) {
+                        *state = Part {
+                            status: Status::Failed,
+                            error: Some(error.clone()),
+                            failure,
+                            ..Part::default()
+                        };
+                    }
+                }
+            }
+        }
+    }
+
+    /// Summary of the parts run so far, rerun parts counting once.
+    fn summary(&self) -> RunSummary {
+        let mut summary = RunSummary::default();
+        for part in self.rows.iter().flat_map(|row| &row.parts) {
+            if !matches!(part.status, Status::Ok | Status::Failed | Status::Mismatch) {
+                continue;
+            }
+            summary.parts += 1;
+            if part.status != Status::Ok {
+                summary.failed += 1;
+                summary.worst = summary.worst.max(part.failure);
+            }
+        }
+        summary
+    }
+
+    /// Forget the results of `days`, about to run again.
+    fn reset(&mut self, days: &[Day]) {
+        for row in self.rows.iter_mut().filter(|row| days.contains(&row.day)) {
+            row.parts = Default::default();
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [table_area, help_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let slowest = self.rows.iter().map(Row::time).max().unwrap_or_default();
+        let rows = self.rows.iter().map(|row| {
+            let mut cells = vec![Cell::from(row.day.to_string()), Cell::from(row.day.title())];
+            for part in &row.parts {
+                let text = format!(
+                    "{} {}",
+                    part.status.symbol(),
+                    part.answer.as_deref().unwrap_or_default()
+                );
+                cells.push(Cell::from(text).style(Style::new().fg(part.status.color())));
+            }
+            let time = row.time();
+            let runtime = match time.is_zero() {
+                true => String::new(),
+                false => format!(
+                    "{:<10} {:>9}",
+                    bar(time.as_secs_f64() / slowest.as_secs_f64(), 10),
+                    format_duration(time)
+                ),
+            };
+            cells.push(Cell::from(runtime));
+            cells.push(
+                Cell::from(row.error().unwrap_or_default()).style(Style::new().fg(Color::Red)),
+            );
+            TableRow::new(cells)
+        });
+        let header = TableRow::new(["Day", "Title", "Part 1", "Part 2", "Runtime", "Last error"])
+            .style(Style::new().add_modifier(Modifier::BOLD));
+        let widths = [
+            Constraint::Length(5),
+            Constraint::Length(24),
+            Constraint::Length(18),
+            Constraint::Length(18),
+            Constraint::Length(20),
+            Constraint::Fill(1),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title(" Advent of Code "))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        let mut state = TableState::new().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, table_area, &mut state);
+        frame.render_widget(
+            Line::from(" ↑↓ select  r rerun  a rerun all  l log  q quit")
+                .style(Style::new().fg(Color::DarkGray)),
+            help_area,
+        );
+
+        if let (true, Some(row)) = (self.show_log, self.rows.get(self.selected)) {
+            let area = centered(frame.area(), 80);
+            let lines = self.logs.lines(&row.day);
+            // keep the last lines in view
+            let scroll = lines
+                .len()
+                .saturating_sub(area.height.saturating_sub(2) as usize);
+            let log = Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<_>>())
+                .block(Block::bordered().title(format!(" {} log, Esc to close ", row.day)))
+                .scroll((scroll as u16, 0));
+            frame.render_widget(Clear, area);
+            frame.render_widget(log, area);
+        }
+    }
+
+    /// Handle key presses while drawing the progress received on `events`,
+    /// sending the days to rerun on `jobs`. Returns when asked to quit.
+    fn event_loop(
+        &mut self,
+        terminal: &mut DefaultTerminal,
+        jobs: &mpsc::Sender<Vec<Day>>,
+        events: &mpsc::Receiver<Event>,
+    ) -> Result<()> {
+        loop {
+            while let Ok(event) = events.try_recv() {
+                self.apply(event);
+            }
+            terminal.draw(|frame| self.draw(frame))?;
+            if !event::poll(Duration::from_millis(100))? {
+                continue;
+            }
+            let TermEvent::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') => return Ok(()),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(());
+                }
+                KeyCode::Esc if self.show_log => self.show_log = false,
+                KeyCode::Esc => return Ok(()),
+                KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1))
+                }
+                KeyCode::Enter | KeyCode::Char('l') => self.show_log = !self.show_log,
+                KeyCode::Char('r') => {
+                    if let Some(row) = self.rows.get(self.selected) {
+                        let days = vec![row.day];
+                        self.reset(&days);
+                        jobs.send(days)?;
+                    }
+                }
+                KeyCode::Char('a') => {
+                    let days: Vec<Day> = self.rows.iter().map(|row| row.day).collect();
+                    self.reset(&days);
+                    jobs.send(days)?;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Bar `width` cells wide filled up to `fraction`, at least a sliver when
+/// there is anything to show.
+fn bar(fraction: f64, width: usize) -> String {
+    const PARTIAL: [&str; 8] = [" ", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+    let eighths = (fraction.clamp(0.0, 1.0) * (width * 8) as f64).round() as usize;
+    let eighths = match fraction > 0.0 {
+        true => eighths.max(1),
+        false => 0,
+    };
+    let mut bar = "█".repeat(eighths / 8);
+    if eighths % 8 > 0 {
+        bar.push_str(PARTIAL[eighths % 8]);
+    }
+    bar
+}
+
+/// Area of `percent` of the width and height of `area`, at its center.
+fn centered(area: Rect, percent: u16) -> Rect {
+    let width = area.width * percent / 100;
+    let height = area.height * percent / 100;
+    Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    )
+}
+
+/// Show the dashboard of the selected `days`, running them all in the
+/// background and again on request, until asked to quit. `logs` must be
+/// where the logs are written, see [`Logs`]. Returns the summary of the
+/// last results shown.
+pub fn run(days: &DaySelection, options: &RunOptions, logs: Logs) -> Result<RunSummary> {
+    if !io::stdout().is_terminal() {
+        bail!("the dashboard needs a terminal");
+    }
+    // before anything runs: the hook catching the panics of the solvers is
+    // installed on first use and must wrap the one restoring the terminal,
+    // for caught panics not to restore it
+    let mut terminal = ratatui::init();
+    let (jobs, job_queue) = mpsc::channel();
+    let (events, progress) = mpsc::channel();
+    {
+        let options = options.clone();
+        let logs = logs.clone();
+        thread::spawn(move || work(&options, &logs, job_queue, events));
+    }
+    let mut dashboard = Dashboard::new(days, logs);
+    let res = jobs
+        .send(days.days().to_vec())
+        .map_err(Into::into)
+        .and_then(|()| dashboard.event_loop(&mut terminal, &jobs, &progress));
+    ratatui::restore();
+    res.map(|()| dashboard.summary())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use crate::{Year, dashboard::*, timing::Timings};
+
+    fn record(day: u8, part: u8, answer: i64, failure: Option<Failure>) -> Record {
+        Record {
+            year: 2025,
+            day,
+            part,
+            answer: Some(answer.into()),
+            timings: Timings {
+                solve: Duration::from_millis(answer as u64),
+                ..Timings::default()
+            },
+            error: None,
+            failure,
+            verdict: None,
+            profile: None,
+            cached: false,
+            input: None,
+        }
+    }
+
+    #[test_log::test]
+    fn test_apply() {
+        let days = DaySelection::parse(Year::Y2025, "1,2").unwrap();
+        let day1 = days.days()[0];
+        let mut dashboard = Dashboard::new(&days, Logs::default());
+        dashboard.apply(Event::Started(day1, 1));
+        assert!(dashboard.rows[0].parts[0].status == Status::Running);
+        dashboard.apply(Event::Record(record(1, 1, 3, None)));
+        dashboard.apply(Event::Record(record(1, 2, 5, Some(Failure::Mismatch))));
+        dashboard.apply(Event::Error(
+            days.days()[1],
+            "invalid parameters".into(),
+            None,
+        ));
+
+        let statuses = |row: &Row| [row.parts[0].status, row.parts[1].status];
+        assert!(statuses(&dashboard.rows[0]) == [Status::Ok, Status::Mismatch]);
+        assert!(statuses(&dashboard.rows[1]) == [Status::Failed, Status::Failed]);
+        assert!(dashboard.rows[0].time() == Duration::from_millis(8));
+        assert!(dashboard.rows[1].error().as_deref() == Some("part 2: invalid parameters"));
+        let summary = dashboard.summary();
+        assert!(summary.parts == 4 && summary.failed == 3);
+        assert!(summary.worst == Some(Failure::Mismatch));
+
+        dashboard.reset(&[day1]);
+        assert!(statuses(&dashboard.rows[0]) == [Status::NotRun, Status::NotRun]);
+        assert!(dashboard.rows[1].parts[0].status == Status::Failed);
+    }
+
+    #[test_log::test]
+    fn test_bar() {
+        assert!(bar(0.0, 4).is_empty());
+        assert!(bar(0.001, 4) == "▏");
+        assert!(bar(0.5, 4) == "██");
+        assert!(bar(0.5625, 4) == "██▎");
+        assert!(bar(1.0, 4) == "████");
+    }
+
+    #[test_log::test]
+    fn test_logs() {
+        let logs = Logs::default();
+        write!(logs.make_writer(), "dropped").unwrap();
+        let day = Year::Y2025.day(3).unwrap();
+        logs.start(&day);
+        write!(logs.make_writer(), "first\nsecond\n").unwrap();
+        assert!(logs.lines(&day) == ["first", "second"]);
+        logs.start(&day);
+        assert!(logs.lines(&day).is_empty());
+    }
+}
@@ -1,144 +0,0 @@
-use std::cmp::{max, min};
-
-use anyhow::Result;
-use itertools::Itertools;
-use tracing::debug;
-
-use crate::{AocRun, load_input_file};
-
-#[derive(Debug, Default)]
-pub struct Day09;
-
-#[derive(Clone, Debug)]
-struct Point {
-    x: i64,
-    y: i64,
-}
-
-fn area(a: &Point, b: &Point) -> i64 {
-    (max(a.x, b.x) - min(a.x, b.x) + 1) * (max(a.y, b.y) - min(a.y, b.y) + 1)
-}
-
-fn process(input: &mut [String]) -> i64 {
-    let mut areas: Vec<i64> = input
-        .iter()
-        .map(|l| Point {
-            x: l.split(',').next().unwrap().parse::<i64>().unwrap(),
-            y: l.split(',').nth(1).unwrap().parse::<i64>().unwrap(),
-        })
-        .tuple_combinations()
-        .map(|elem: (Point, Point)| area(&elem.0, &elem.1))
-        .collect();
-
-    areas.sort();
-    debug!(?areas);
-    *areas.iter().next_back().unwrap()
-}
-
-fn process2(input: &mut Vec<String>) -> i64 {
-    let mut rectangles: Vec<(Point, Point)> = input
-        .iter()
-        .map(|l| Point {
-            x: l.split(',').next().unwrap().parse::<i64>().unwrap(),
-            y: l.split(',').nth(1).unwrap().parse::<i64>().unwrap(),
-        })
-        .tuple_combinations()
-        .collect();
-
-    rectangles.sort_by_key(|rect| area(&rect.0, &rect.1));
-    rectangles.reverse();
-
-    input.push(input[0].clone());
-    for rect in rectangles {
-        debug!(?rect);
-        let mut bad = false;
-        for (a, b) in input.iter().tuple_windows() {
-            let a = Point {
-                x: a.split(',').next().unwrap().parse::<i64>().unwrap(),
-                y: a.split(',').nth(1).unwrap().parse::<i64>().unwrap(),
-            };
-            let b = Point {
-                x: b.split(',').next().unwrap().parse::<i64>().unwrap(),
-                y: b.split(',').nth(1).unwrap().parse::<i64>().unwrap(),
-            };
-            // debug!(?a, ?b);
-
-            // intersection test
-            if min(a.x, b.x) < max(rect.0.x, rect.1.x)
-                && min(a.y, b.y) < max(rect.0.y, rect.1.y)
-                && max(a.x, b.x) > min(rect.0.x, rect.1.x)
-                && max(a.y, b.y) > min(rect.0.y, rect.1.y)
-            {
-                debug!("intersection...");
-                bad = true;
-                break;
-            }
-        }
-        if bad {
-            continue;
-        } else {
-            debug!(?rect, "Found area: {}", area(&rect.0, &rect.1));
-            return area(&rect.0, &rect.1);
-        }
-    }
-
-    0
-}
-
-impl AocRun for Day09 {
-    fn run1(&self) -> Result<i64> {
-        let res = process(&mut load_input_file("./input/day09.txt")?);
-        Ok(res as i64)
-    }
-    fn run2(&self) -> Result<i64> {
-        let res = process2(&mut load_input_file("./input/day09.txt")?);
-        Ok(res as i64)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::{day09::*, tests::load_test_input};
-
-    //   0 1 2 3 4 5 6 7 8 9 0 1 2 3
-    // 0 . . . . . . . . . . . . . .
-    // 1 . . . . . . . # . . . # . .
-    // 2 . . . . . . . . . . . . . .
-    // 3 . . # . . . . # . . . . . .
-    // 4 . . . . . . . . . . . . . .
-    // 5 . . # . . . . . . # . . . .
-    // 6 . . . . . . . . . . . . . .
-    // 7 . . . . . . . . . # . # . .
-    // 8 . . . . . . . . . . . . . .
-    //
-    //   0 1 2 3 4 5 6 7 8 9 0 1 2 3
-    // 0 . . . . . . . . . . . . . .
-    // 1 . . . . . . . # X X X # . .
-    // 2 . . . . . . . X . . . X . .
-    // 3 . . # X X X X # . . . X . .
-    // 4 . . X . . . . . . . . X . .
-    // 5 . . # X X X X X X # . X . .
-    // 6 . . . . . . . . . X . X . .
-    // 7 . . . . . . . . . # X # . .
-    // 8 . . . . . . . . . . . . . .
-    static TEST_INPUT: &str = r#"
-7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3
-"#;
-
-    #[test_log::test]
-    fn test1() {
-        assert!(process(&mut load_test_input(TEST_INPUT)) == 50);
-    }
-
-    #[test_log::test]
-    fn test2() {
-        assert!(process2(&mut load_test_input(TEST_INPUT)) == 24);
-    }
-}
@@ -0,0 +1,76 @@
+use std::fmt;
+
+use strum_macros::Display;
+
+/// Why a run or a part failed. Each kind maps to its own process exit code
+/// so that scripts can tell them apart; when several parts fail, the
+/// highest code wins.
+#[derive(Clone, Copy, Debug, Display, Eq, Ord, PartialEq, PartialOrd)]
+#[strum(serialize_all = "kebab-case")]
+#[repr(u8)]
+pub enum Failure {
+    UnknownDay = 3,
+    MissingInput = 4,
+    Parse = 5,
+    Solver = 6,
+    Mismatch = 7,
+    Panic = 8,
+}
+
+impl Failure {
+    pub fn exit_code(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn error(self, message: impl Into<String>) -> anyhow::Error {
+        AocError {
+            kind: self,
+            message: message.into(),
+        }
+        .into()
+    }
+
+    /// Kind of failure carried by `error`, if it was raised through
+    /// [`Failure::error`].
+    pub fn find(error: &anyhow::Error) -> Option<Failure> {
+        error
+            .chain()
+            .find_map(|e| e.downcast_ref::<AocError>())
+            .map(|e| e.kind)
+    }
+}
+
+#[derive(Debug)]
+struct AocError {
+    kind: Failure,
+    message: String,
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use crate::error::*;
+
+    #[test_log::test]
+    fn test_failure_find() {
+        let e = Failure::MissingInput.error("no such file");
+        assert!(Failure::find(&e) == Some(Failure::MissingInput));
+        assert!(e.to_string() == "no such file");
+
+        let e = Failure::Parse.error("empty input").context("day 2");
+        assert!(Failure::find(&e) == Some(Failure::Parse));
+
+        assert!(Failure::find(&anyhow!("No part 2!")).is_none());
+        assert!(Failure::Panic.exit_code() == 8);
+        assert!(Failure::UnknownDay.to_string() == "unknown-day");
+    }
+}
@@ -0,0 +1,231 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::{
+    Day, InputSource, Parts, RunOptions, RunSummary, Year,
+    answers::Answers,
+    report::{Reporter, Tally},
+    selection::DaySelection,
+};
+
+/// Example of a puzzle statement, stored as files:
+/// `examples/<year>/dayNN/<name>.in` is the puzzle input and `<name>.out`
+/// holds the expected answers, along with the parameters to solve it with:
+///
+/// ```toml
+/// part1 = 40
+/// part2 = 25272
+///
+/// [params]
+/// connections = 10
+/// ```
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Fixture {
+    pub day: Day,
+    /// Path of the `.in` file.
+    pub input: PathBuf,
+    /// Contents of the `.in` and `.out` files, when embedded in the library.
+    embedded: Option<(&'static str, Option<&'static str>)>,
+}
+
+/// Fixtures of the `examples` directory of the crate as of the build: their
+/// path relative to it, input and expected answers. Generated by `build.rs`.
+static EMBEDDED: &[(&str, &str, Option<&str>)] = include!(concat!(env!("OUT_DIR"), "/fixtures.rs"));
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<toml::Value>,
+    part2: Option<toml::Value>,
+    #[serde(default)]
+    params: BTreeMap<String, toml::Value>,
+}
+
+fn text(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn year_of(dir: &Path) -> Result<Year> {
+    file_name(dir)
+        .parse()
+        .with_context(|| format!("unexpected fixture directory {}", dir.display()))
+}
+
+fn day_of(year: Year, dir: &Path) -> Result<Day> {
+    file_name(dir)
+        .strip_prefix("day")
+        .and_then(|n| n.parse().ok())
+        .and_then(|n| year.day(n))
+        .with_context(|| format!("unexpected fixture directory {}", dir.display()))
+}
+
+impl Fixture {
+    /// Fixtures of the `examples` directory at `dir`, in year, day and name
+    /// order.
+    pub fn discover(dir: &Path) -> Result<Vec<Fixture>> {
+        let mut fixtures = vec![];
+        let entries =
+            fs::read_dir(dir).with_context(|| format!("failed to list {}", dir.display()))?;
+        for entry in entries {
+            let year_dir = entry?.path();
+            let year = year_of(&year_dir)?;
+            for entry in fs::read_dir(&year_dir)? {
+                let dir = entry?.path();
+                let day = day_of(year, &dir)?;
+                for entry in fs::read_dir(&dir)? {
+                    let input = entry?.path();
+                    if input.extension().is_some_and(|e| e == "in") {
+                        fixtures.push(Fixture {
+                            day,
+                            input,
+                            embedded: None,
+                        });
+                    }
+                }
+            }
+        }
+        fixtures.sort();
+        Ok(fixtures)
+    }
+
+    /// Fixtures embedded in the library when it was built, in year, day and
+    /// name order. Their paths are those of the crate they came from.
+    pub fn embedded() -> Result<Vec<Fixture>> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        EMBEDDED
+            .iter()
+            .map(|&(path, input, expected)| {
+                let input_path = root.join(path);
+                let dir = input_path.parent().unwrap_or(&root);
+                let year = year_of(dir.parent().unwrap_or(&root))?;
+                Ok(Fixture {
+                    day: day_of(year, dir)?,
+                    input: input_path.clone(),
+                    embedded: Some((input, expected)),
+                })
+            })
+            .collect()
+    }
+
+    pub fn name(&self) -> String {
+        self.input
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// File holding the expected answers.
+    pub fn expected(&self) -> PathBuf {
+        self.input.with_extension("out")
+    }
+
+    /// `options` changed to run the fixture and check its answers, limited
+    /// to the parts having an expected answer. `None` when none of them is
+    /// among the parts of `options`.
+    pub fn options(&self, options: &RunOptions) -> Result<Option<RunOptions>> {
+        let out = self.expected();
+        let content = match self.embedded {
+            Some((_, expected)) => expected.map(str::to_string),
+            None => fs::read_to_string(&out).ok(),
+        }
+        .with_context(|| format!("missing expected answers {}", out.display()))?;
+        let expected: Expected =
+            toml::from_str(&content).with_context(|| format!("invalid {}", out.display()))?;
+
+        let mut answers = Answers::default();
+        for (part, value) in [(1, &expected.part1), (2, &expected.part2)] {
+            if let Some(value) = value {
+                answers.set(&self.day, part, text(value));
+            }
+        }
+        let parts = match (&expected.part1, &expected.part2) {
+            (Some(_), None) => Parts::One,
+            (None, Some(_)) => Parts::Two,
+            _ => Parts::Both,
+        };
+        let parts = match (options.parts, parts) {
+            (Parts::Both, parts) | (parts, Parts::Both) => parts,
+            (a, b) if a == b => a,
+            _ => return Ok(None),
+        };
+        let mut params = options.params.clone();
+        let day_params = params.entry(self.day).or_default();
+        for (key, value) in &expected.params {
+            day_params.set(key, text(value));
+        }
+        Ok(Some(RunOptions {
+            input: Some(match self.embedded {
+                Some((input, _)) => InputSource::Example(input),
+                None => InputSource::File(self.input.clone()),
+            }),
+            params,
+            parts,
+            answers: Some(answers),
+            ..options.clone()
+        }))
+    }
+}
+
+/// Run the selected `days` on their `fixtures`, checking the answers
+/// against the expected ones. A part is run once per fixture expecting an
+/// answer.
+pub fn run(
+    days: &DaySelection,
+    options: &RunOptions,
+    fixtures: &[Fixture],
+    reporter: &mut dyn Reporter,
+) -> Result<RunSummary> {
+    let mut tally = Tally::new(reporter);
+    let reporter = &mut tally;
+    reporter.begin(&format!("examples of {days}"));
+    for day in days.days() {
+        let mut examples = vec![];
+        for fixture in fixtures.iter().filter(|fixture| fixture.day == *day) {
+            examples.extend(fixture.options(options)?);
+        }
+        reporter.day(day);
+        for part in 1..=2 {
+            let examples: Vec<&RunOptions> = examples
+                .iter()
+                .filter(|example| example.runs(day, part) && expects(example, day, part))
+                .collect();
+            if examples.is_empty() {
+                reporter.part_skipped(day, part);
+                continue;
+            }
+            for example in examples {
+                reporter.part_started(day, part);
+                let module = example.module(day)?;
+                let mut loaded = module.load(&example.input_for(day));
+                reporter.record(module.record_part(day, part, &mut loaded, example));
+            }
+        }
+    }
+    reporter.end();
+    Ok(tally.summary)
+}
+
+/// Whether the fixture run with `options` has an expected answer for `part`.
+fn expects(options: &RunOptions, day: &Day, part: u8) -> bool {
+    options
+        .answers
+        .as_ref()
+        .is_some_and(|answers| answers.expected(day, part).is_some())
+}
@@ -0,0 +1,346 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{params::Params, report::Record, selection::DaySelection, timing::format_duration};
+
+/// A part solved by a run, as logged in the history file.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Entry {
+    /// Seconds since the epoch.
+    pub timestamp: u64,
+    /// Git revision of the working tree, if it is one.
+    pub rev: Option<String>,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Hash of the input, as used by the result cache.
+    pub input: String,
+    /// Parameters the day was solved with. Empty in entries logged before
+    /// they were.
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    pub profile: Option<String>,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub read_ns: u64,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub total_ns: u64,
+}
+
+impl Entry {
+    fn total(&self) -> Duration {
+        Duration::from_nanos(self.total_ns)
+    }
+}
+
+/// Log of the parts solved, one JSON line per part appended to a file.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    rev: Option<String>,
+    lock: Mutex<()>,
+}
+
+impl History {
+    pub fn new(path: &Path) -> Self {
+        History {
+            path: path.to_path_buf(),
+            rev: git_rev(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Log `record`, solved with `params`, unless its answer came from the
+    /// cache or its input is unknown. Failing to write only warns.
+    pub fn append(&self, record: &Record, params: &Params) {
+        let Some(input) = record.input.clone().filter(|_| !record.cached) else {
+            return;
+        };
+        let entry = Entry {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            rev: self.rev.clone(),
+            year: record.year,
+            day: record.day,
+            part: record.part,
+            input,
+            params: params
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            profile: record.profile.clone(),
+            answer: record.answer.as_ref().map(|a| a.to_string()),
+            error: record.error.clone(),
+            read_ns: record.timings.read.as_nanos() as u64,
+            parse_ns: record.timings.parse.as_nanos() as u64,
+            solve_ns: record.timings.solve.as_nanos() as u64,
+            total_ns: record.timings.total().as_nanos() as u64,
+        };
+        let _lock = self.lock.lock().unwrap();
+        if let Err(e) = self.write(&entry) {
+            warn!("failed to log the run in {}: {e:#}", self.path.display());
+        }
+    }
+
+    fn write(&self, entry: &Entry) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        Ok(())
+    }
+
+    /// Entries logged in the file at `path`, oldest first.
+    pub fn load(path: &Path) -> Result<Vec<Entry>> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e).with_context(|| format!("failed to read {}", path.display())),
+        };
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(n, line)| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("invalid entry at {}:{}", path.display(), n + 1))
+            })
+            .collect()
+    }
+}
+
+/// Short revision of the git repository in the current directory, marked
+/// dirty when it has uncommitted changes.
+fn git_rev() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+    let rev = String::from_utf8(output.stdout).ok()?;
+    (output.status.success() && !rev.trim().is_empty()).then(|| rev.trim().to_string())
+}
+
+/// An entry along with how it compares to the previous one for the same
+/// part, input and parameters.
+pub struct Change<'a> {
+    pub entry: &'a Entry,
+    /// Answer of the previous entry, when this one differs from it.
+    pub regression: Option<Option<&'a str>>,
+    /// Runtime of the previous entry.
+    pub previous: Option<Duration>,
+}
+
+/// Entries of the selected `days`, grouped by part in day order, each
+/// compared to the previous entry for the same input and parameters, so
+/// that changing the parameters of a day doesn't count as a regression
+/// while changing its code does.
+pub fn changes<'a>(entries: &'a [Entry], days: &DaySelection) -> Vec<Change<'a>> {
+    let mut selected: Vec<&Entry> = entries
+        .iter()
+        .filter(|e| e.year == days.year() as u16)
+        .filter(|e| days.days().iter().any(|d| d.number() == e.day))
+        .collect();
+    selected.sort_by_key(|e| (e.day, e.part));
+
+    type Key<'a> = (u8, u8, &'a str, &'a BTreeMap<String, String>);
+    let mut last: HashMap<Key, &Entry> = HashMap::new();
+    selected
+        .into_iter()
+        .map(|entry| {
+            let previous = last.insert((entry.day, entry.part, &entry.input, &entry.params), entry);
+            Change {
+                entry,
+                regression: previous
+                    .filter(|p| p.answer.is_some() && p.answer != entry.answer)
+                    .map(|p| p.answer.as_deref()),
+                previous: previous.map(Entry::total),
+            }
+        })
+        .collect()
+}
+
+/// Show the entries of the selected `days` one part after the other, with
+/// how their runtimes changed and the answers that changed flagged.
+/// Returns the number of answers that changed.
+pub fn show(entries: &[Entry], days: &DaySelection) -> usize {
+    let mut regressions = 0;
+    let mut part = None;
+    for change in changes(entries, days) {
+        let entry = change.entry;
+        if part != Some((entry.day, entry.part)) {
+            part = Some((entry.day, entry.part));
+            println!("Day{:02} part {}:", entry.day, entry.part);
+        }
+        let answer = match (&entry.answer, &entry.error) {
+            (Some(answer), _) => answer.clone(),
+            (None, error) => format!("✖ {}", error.as_deref().unwrap_or_default()),
+        };
+        let trend = match change.previous {
+            Some(previous) if !previous.is_zero() => {
+                let ratio = entry.total().as_secs_f64() / previous.as_secs_f64();
+                format!(" ({:+.0}%)", (ratio - 1.0) * 100.0)
+            }
+            _ => String::new(),
+        };
+        let flag = match change.regression {
+            Some(previous) => {
+                regressions += 1;
+                format!(
+                    "  ⚠ answer changed for the same input, was {}",
+                    previous.unwrap_or("none")
+                )
+            }
+            None => String::new(),
+        };
+        println!(
+            "  {}  {:<16}  {:>8}  {}  {} in {}{}{}",
+            format_timestamp(entry.timestamp),
+            entry.rev.as_deref().unwrap_or("-"),
+            &entry.input[..8.min(entry.input.len())],
+            entry.profile.as_deref().unwrap_or("default"),
+            answer,
+            format_duration(entry.total()),
+            trend,
+            flag
+        );
+    }
+    regressions
+}
+
+/// `secs` since the epoch as a UTC date and time.
+fn format_timestamp(secs: u64) -> String {
+    // days to civil date, from Howard Hinnant's algorithms
+    let days = (secs / 86400) as i64 + 719468;
+    let era = days.div_euclid(146097);
+    let doe = days.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    let time = secs % 86400;
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Year, history::*};
+
+    fn entry(timestamp: u64, day: u8, input: &str, answer: &str) -> Entry {
+        Entry {
+            timestamp,
+            rev: None,
+            year: 2025,
+            day,
+            part: 1,
+            input: input.to_string(),
+            params: BTreeMap::new(),
+            profile: None,
+            answer: Some(answer.to_string()),
+            error: None,
+            read_ns: 0,
+            parse_ns: 0,
+            solve_ns: 0,
+            total_ns: 1000,
+        }
+    }
+
+    #[test_log::test]
+    fn test_changes() {
+        let entries = vec![
+            entry(1, 1, "a", "10"),
+            entry(2, 1, "b", "20"),
+            entry(3, 1, "a", "10"),
+            entry(4, 2, "a", "5"),
+            // the solver changed
+            Entry {
+                rev: Some("abc1234".into()),
+                ..entry(5, 1, "a", "11")
+            },
+            // other parameters
+            Entry {
+                params: BTreeMap::from([("connections".into(), "10".into())]),
+                ..entry(6, 1, "a", "12")
+            },
+        ];
+        let days = DaySelection::all(Year::Y2025);
+        let changes = changes(&entries, &days);
+        let regressions: Vec<_> = changes
+            .iter()
+            .map(|c| (c.entry.timestamp, c.regression))
+            .collect();
+        assert!(
+            regressions
+                == vec![
+                    (1, None),
+                    (2, None),
+                    (3, None),
+                    (5, Some(Some("10"))),
+                    (6, None),
+                    (4, None)
+                ]
+        );
+        let days = DaySelection::parse(Year::Y2025, "2").unwrap();
+        assert!(super::changes(&entries, &days).len() == 1);
+    }
+
+    #[test_log::test]
+    fn test_format_timestamp() {
+        assert!(format_timestamp(0) == "1970-01-01 00:00:00");
+        assert!(format_timestamp(1764547200) == "2025-12-01 00:00:00");
+        assert!(format_timestamp(1709210096) == "2024-02-29 12:34:56");
+    }
+
+    #[test_log::test]
+    fn test_append_load() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let history = History::new(&path);
+        let mut record = Record {
+            year: 2025,
+            day: 3,
+            part: 2,
+            answer: Some(42.into()),
+            timings: Default::default(),
+            error: None,
+            failure: None,
+            verdict: None,
+            profile: None,
+            cached: false,
+            input: Some("0123456789abcdef".into()),
+        };
+        let params: Params = [("connections", "10")].into_iter().collect();
+        history.append(&record, &params);
+        record.cached = true;
+        history.append(&record, &params);
+        let entries = History::load(&path).unwrap();
+        assert!(entries.len() == 1);
+        assert!(entries[0].answer.as_deref() == Some("42"));
+        assert!(entries[0].input == "0123456789abcdef");
+        assert!(entries[0].params["connections"] == "10");
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,161 +1,702 @@
 use std::{
-    fs::File,
-    io::{BufRead, BufReader, Lines},
-    path::Path,
-    time,
+    any::Any,
+    collections::HashMap,
+    convert::Infallible,
+    fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
 };
 
-use anyhow::Result;
-use nanospinner::Spinner;
-use strum::{EnumIter, FromRepr, IntoEnumIterator};
+use anyhow::{Result, anyhow, bail};
+use itertools::Itertools;
+use strum::{EnumIter, EnumString, FromRepr, IntoEnumIterator};
 use strum_macros::Display;
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod cache;
+pub mod client;
+pub mod config;
+pub mod dashboard;
+pub mod error;
+pub mod examples;
+pub mod history;
+mod panics;
+mod parallel;
+pub mod params;
+pub mod profiles;
+pub mod repl;
+pub mod report;
+pub mod scaffold;
+pub mod selection;
+pub mod timing;
 mod utils;
+pub mod watch;
 
-pub trait AocRun {
-    fn run(&self, name: &str) -> Result<()> {
-        println!("{name}:");
-        let spinner = Spinner::new(" Running part 1").start();
-        let start = time::Instant::now();
-        match self.run1() {
-            Ok(res) => spinner.success_with(format!(
-                " Part 1: {} in {:.2} us",
-                res,
-                start.elapsed().as_micros()
-            )),
-            Err(_) => spinner.fail(),
-        }
-        let spinner = Spinner::new(" Running part 2").start();
-        match self.run2() {
-            Ok(res) => spinner.success_with(format!(
-                " Part 2: {} in {:.2} us",
-                res,
-                start.elapsed().as_micros()
-            )),
-            Err(e) => spinner.fail_with(format!(" {}", e)),
+pub use answer::Answer;
+use answers::{Answers, Verdict};
+use cache::ResultCache;
+use error::Failure;
+use history::History;
+use params::Params;
+use report::{Record, Reporter, Tally};
+use selection::DaySelection;
+use timing::{Phase, Timings};
+
+/// Where a day reads its puzzle input from.
+#[derive(Clone, Debug)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// Input embedded in the library, such as an example fixture.
+    Example(&'static str),
+}
+
+impl InputSource {
+    /// Input of a day stored in `dir`, e.g. `input/day08.txt`.
+    pub fn for_day(day: &Day, dir: &Path) -> Self {
+        InputSource::File(dir.join(format!("{}.txt", day.to_string().to_lowercase())))
+    }
+
+    fn read_to_string(&self) -> Result<String> {
+        // stdin can only be consumed once, keep it around for the next part
+        static STDIN: OnceLock<String> = OnceLock::new();
+
+        match self {
+            InputSource::File(path) => fs::read_to_string(path)
+                .map_err(|e| Failure::MissingInput.error(format!("{}: {}", path.display(), e))),
+            InputSource::Stdin => {
+                if let Some(content) = STDIN.get() {
+                    return Ok(content.clone());
+                }
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(|e| Failure::MissingInput.error(format!("stdin: {}", e)))?;
+                Ok(STDIN.get_or_init(|| content).clone())
+            }
+            InputSource::Example(input) => Ok(input.to_string()),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(InputSource::Stdin),
+            _ => Ok(InputSource::File(PathBuf::from(s))),
+        }
+    }
+}
+
+/// Which part(s) of a day to run.
+#[derive(Clone, Copy, Debug, Default, Display, EnumString, PartialEq)]
+pub enum Parts {
+    #[strum(serialize = "1")]
+    One,
+    #[strum(serialize = "2")]
+    Two,
+    #[default]
+    #[strum(serialize = "both")]
+    Both,
+}
+
+impl Parts {
+    pub fn includes(&self, part: u8) -> bool {
+        matches!(
+            (self, part),
+            (Parts::Both, _) | (Parts::One, 1) | (Parts::Two, 2)
+        )
+    }
+}
+
+/// How the selected days are run.
+#[derive(Clone, Debug)]
+pub struct RunOptions {
+    /// Puzzle input replacing `dayNN.txt` in `input_dir`.
+    pub input: Option<InputSource>,
+    /// Holds the inputs of each year, see [`Year::input_dir`].
+    pub input_dir: PathBuf,
+    /// Parameters handed to the days before running them.
+    pub params: HashMap<Day, Params>,
+    pub parts: Parts,
+    /// Expected answers to check the results against.
+    pub answers: Option<Answers>,
+    /// Number of worker threads, days run one after the other below 2.
+    pub jobs: usize,
+    /// Schedule the parts of a day as separate jobs.
+    pub split_parts: bool,
+    /// Name of the input set in use, if not the default one.
+    pub profile: Option<String>,
+    /// Cache the answers are stored in, if any.
+    pub cache: Option<Arc<ResultCache>>,
+    /// Take the answers found in `cache` instead of solving the parts.
+    pub cached: bool,
+    /// Log the parts solved are appended to, if any.
+    pub history: Option<Arc<History>>,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            input: None,
+            input_dir: PathBuf::from("input"),
+            params: HashMap::new(),
+            parts: Parts::default(),
+            answers: None,
+            jobs: 1,
+            split_parts: false,
+            profile: None,
+            cache: None,
+            cached: false,
+            history: None,
         }
+    }
+}
+
+impl RunOptions {
+    /// Whether `part` of `day` is to be run.
+    pub fn runs(&self, day: &Day, part: u8) -> bool {
+        self.parts.includes(part) && day.has_part(part)
+    }
+
+    pub fn input_for(&self, day: &Day) -> InputSource {
+        match &self.input {
+            Some(input) => input.clone(),
+            None => InputSource::for_day(day, &day.year().input_dir(&self.input_dir)),
+        }
+    }
+
+    /// Hash of `input`, when needed to cache or log the answers.
+    fn input_hash(&self, input: &InputSource) -> Option<String> {
+        if self.cache.is_none() && self.history.is_none() {
+            return None;
+        }
+        input
+            .read_to_string()
+            .ok()
+            .map(|content| cache::hash(&content))
+    }
+
+    /// Solver of `day`, configured with its parameters.
+    pub fn module(&self, day: &Day) -> Result<Box<dyn AocRun>> {
+        let mut module: Box<dyn AocRun> = (*day).into();
+        if let Some(params) = self.params.get(day) {
+            module
+                .configure(params)
+                .map_err(|e| e.context(format!("invalid parameters for {day}")))?;
+        }
+        Ok(module)
+    }
+}
+
+/// Solver of a day, written against its own parsed input: the puzzle input
+/// is parsed once and both parts are solved from the result.
+pub trait Solution: Send + Sync {
+    type Input: Send + Sync + 'static;
+
+    /// Apply parameters such as those of the `[dayNN]` table of `aoc.toml`.
+    fn configure(&mut self, _params: &Params) -> Result<()> {
         Ok(())
     }
-    fn run1(&self) -> Result<i64>;
-    fn run2(&self) -> Result<i64>;
-}
-
-#[derive(Debug, Display, EnumIter, FromRepr)]
-#[repr(u8)]
-pub enum Day {
-    #[strum(to_string = "all days")]
-    Day00,
-    Day01,
-    Day02,
-    Day03,
-    Day04,
-    Day05,
-    Day06,
-    Day07,
-    Day08,
-    Day09,
-    Day10,
-    Day11,
-    Day12,
+    /// Number of the day in the calendar.
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Parts of the puzzle the day solves, the last day having no part 2.
+    const PARTS: &'static [u8] = &[1, 2];
+    /// Commands of `aoc repl` exploring the parsed input, each as a usage
+    /// such as `query ID` along with what it does.
+    const COMMANDS: &'static [(&'static str, &'static str)] = &[];
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        bail!("{} has no part 2", Self::TITLE)
+    }
+    /// Run the command `name` of [`Self::COMMANDS`] with its `args`.
+    fn command(&self, _input: &Self::Input, name: &str, _args: &[&str]) -> Result<Answer> {
+        bail!("unknown command {name}")
+    }
 }
 
-impl From<Day> for Box<dyn AocRun> {
-    fn from(value: Day) -> Self {
-        match value {
-            Day::Day00 => Box::new(Day00),
-            Day::Day01 => Box::new(day01::Day01),
-            Day::Day02 => Box::new(day02::Day02),
-            Day::Day03 => Box::new(day03::Day03),
-            Day::Day04 => Box::new(day04::Day04),
-            Day::Day05 => Box::new(day05::Day05),
-            Day::Day06 => Box::new(day06::Day06),
-            Day::Day07 => Box::new(day07::Day07),
-            Day::Day08 => Box::new(day08::Day08),
-            Day::Day09 => Box::new(day09::Day09),
-            Day::Day10 => Box::new(day10::Day10),
-            Day::Day11 => Box::new(day11::Day11),
-            Day::Day12 => Box::new(day12::Day12),
-        }
-    }
-}
-
-struct Day00;
-
-impl AocRun for Day00 {
-    fn run(&self, _name: &str) -> Result<()> {
-        for day in Day::iter() {
-            match day {
-                Day::Day00 => (),
-                _ => {
-                    let name = &day.to_string();
-                    let module: Box<dyn AocRun> = day.into();
-                    module.run(name)?;
-                }
+/// Parsed input of a day, as handed to [`AocRun::solve`].
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// Input read and parsed for the parts of a run, or why it couldn't be.
+pub struct Loaded {
+    input: Result<Parsed, (String, Failure)>,
+    /// Read and parse timings, reported with the first part solved.
+    timings: Option<Timings>,
+}
+
+/// Object safe face of a [`Solution`], so that days can be picked at
+/// runtime.
+pub trait AocRun: Send + Sync {
+    fn configure(&mut self, params: &Params) -> Result<()>;
+    fn parse(&self, input: &str) -> Result<Parsed>;
+    fn solve(&self, part: u8, input: &Parsed) -> Result<Answer>;
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+    fn command(&self, input: &Parsed, name: &str, args: &[&str]) -> Result<Answer>;
+
+    /// Read and parse `input`, timing both phases. Parsing errors that
+    /// don't tell their kind are parse failures.
+    fn load(&self, input: &InputSource) -> Loaded {
+        let (res, timings) = timing::timed(|| {
+            panics::catch(|| {
+                let content = timing::measure(Phase::Read, || input.read_to_string())?;
+                timing::measure(Phase::Parse, || self.parse(&content)).map_err(|e| {
+                    match Failure::find(&e) {
+                        Some(_) => e,
+                        None => Failure::Parse.error(format!("{e:#}")),
+                    }
+                })
+            })
+        });
+        Loaded {
+            input: outcome(res),
+            timings: Some(Timings {
+                solve: Duration::ZERO,
+                ..timings
+            }),
+        }
+    }
+    fn run(
+        &self,
+        day: &Day,
+        input: &InputSource,
+        options: &RunOptions,
+        reporter: &mut dyn Reporter,
+    ) -> Result<()> {
+        reporter.day(day);
+        let mut loaded = None;
+        let hash = options.input_hash(input);
+        for part in 1..=2 {
+            if !options.runs(day, part) {
+                reporter.part_skipped(day, part);
+                continue;
             }
+            reporter.part_started(day, part);
+            reporter.record(self.run_part(day, part, input, &mut loaded, hash.as_deref(), options));
         }
         Ok(())
     }
-    fn run1(&self) -> Result<i64> {
-        Ok(0)
+    /// Record of `part`, taken from the cache when asked to, solved from
+    /// `loaded` otherwise, loading it on first use. `hash` is the hash of
+    /// `input`, needed to cache the answer and log it.
+    fn run_part(
+        &self,
+        day: &Day,
+        part: u8,
+        input: &InputSource,
+        loaded: &mut Option<Loaded>,
+        hash: Option<&str>,
+        options: &RunOptions,
+    ) -> Record {
+        let cache = options
+            .cache
+            .as_ref()
+            .zip(hash.map(|hash| ResultCache::key(day, hash, options)));
+        let cached = cache
+            .as_ref()
+            .filter(|_| options.cached)
+            .and_then(|(cache, key)| cache.get(key, part));
+        let record = match cached {
+            Some(answer) => Record {
+                cached: true,
+                ..record(day, part, Ok(answer), Timings::default(), options)
+            },
+            None => {
+                let loaded = loaded.get_or_insert_with(|| self.load(input));
+                self.record_part(day, part, loaded, options)
+            }
+        };
+        let record = Record {
+            input: hash.map(String::from),
+            ..record
+        };
+        if let (Some((cache, key)), Some(answer), false) = (&cache, &record.answer, record.cached) {
+            cache.put(key, part, answer);
+        }
+        if let Some(history) = &options.history {
+            history.append(
+                &record,
+                &options.params.get(day).cloned().unwrap_or_default(),
+            );
+        }
+        record
     }
-    fn run2(&self) -> Result<i64> {
-        Ok(0)
+    fn record_part(
+        &self,
+        day: &Day,
+        part: u8,
+        loaded: &mut Loaded,
+        options: &RunOptions,
+    ) -> Record {
+        let (res, mut timings) = match &loaded.input {
+            Ok(input) => {
+                let (res, timings) = timing::timed(|| panics::catch(|| self.solve(part, input)));
+                (outcome(res), timings)
+            }
+            Err(e) => (Err(e.clone()), Timings::default()),
+        };
+        if let Some(load) = loaded.timings.take() {
+            timings.read += load.read;
+            timings.parse += load.parse;
+        }
+        record(day, part, res, timings, options)
     }
 }
 
-pub fn run(day: u8) -> Result<()> {
-    if let Some(day) = Day::from_repr(day) {
-        let name = &day.to_string();
-        println!("Running {}", name);
-        let module: Box<dyn AocRun> = day.into();
-        module.run(name)?;
+/// Record of `part` of `day` given its outcome, checked against the
+/// expected answers of `options`.
+fn record(
+    day: &Day,
+    part: u8,
+    res: Result<Answer, (String, Failure)>,
+    timings: Timings,
+    options: &RunOptions,
+) -> Record {
+    let (answer, error, failure) = match res {
+        Ok(answer) => (Some(answer), None, None),
+        Err((error, failure)) => (None, Some(error), Some(failure)),
     };
-    Ok(())
+    let verdict = options
+        .answers
+        .as_ref()
+        .map(|answers| answers.check(day, part, answer.as_ref().map(|a| a.to_string()).as_deref()));
+    let failure = match (failure, &verdict) {
+        (None, Some(Verdict::Fail { .. })) => Some(Failure::Mismatch),
+        (failure, _) => failure,
+    };
+    Record {
+        year: day.year() as u16,
+        day: day.number(),
+        part,
+        answer,
+        timings,
+        error,
+        failure,
+        verdict,
+        profile: options.profile.clone(),
+        cached: false,
+        input: None,
+    }
+}
+
+impl<S: Solution> AocRun for S {
+    fn configure(&mut self, params: &Params) -> Result<()> {
+        Solution::configure(self, params)
+    }
+    fn parse(&self, input: &str) -> Result<Parsed> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+    fn solve(&self, part: u8, input: &Parsed) -> Result<Answer> {
+        let input = downcast::<S>(input)?;
+        match part {
+            1 => self.part1(input),
+            _ => self.part2(input),
+        }
+    }
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        S::COMMANDS
+    }
+    fn command(&self, input: &Parsed, name: &str, args: &[&str]) -> Result<Answer> {
+        Solution::command(self, downcast::<S>(input)?, name, args)
+    }
 }
 
-pub fn load_input_file(filename: impl AsRef<Path>) -> Result<Vec<String>> {
-    Ok(read_lines(filename)?
-        .map(|l| l.expect("Failed to parse line"))
-        .collect())
+fn downcast<S: Solution>(input: &Parsed) -> Result<&S::Input> {
+    input
+        .downcast_ref::<S::Input>()
+        .ok_or_else(|| anyhow!("input parsed by another day"))
 }
 
-pub fn single_line_from_file(filename: impl AsRef<Path>) -> Result<String> {
-    Ok(read_lines(filename)?.next().unwrap()?)
+/// Error message and kind of failure of a step that returned an error or
+/// panicked.
+fn outcome<T>(res: Result<Result<T>, String>) -> Result<T, (String, Failure)> {
+    match res {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err((e.to_string(), Failure::find(&e).unwrap_or(Failure::Solver))),
+        Err(panic) => Err((panic, Failure::Panic)),
+    }
 }
 
-fn read_lines<P>(filename: P) -> Result<Lines<BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(BufReader::new(file).lines())
+/// A day of a year having a solver, as declared by [`days!`].
+pub(crate) struct Entry {
+    number: u8,
+    title: &'static str,
+    parts: &'static [u8],
+    solver: fn() -> Box<dyn AocRun>,
+    source: &'static str,
+}
+
+/// Declare the days of a year: their modules and solvers. Each module
+/// tells its number, title and parts through its [`Solution`], so adding a
+/// day is a matter of adding its line to the year's list.
+macro_rules! days {
+    ($($module:ident::$solver:ident),* $(,)?) => {
+        $(mod $module;)*
+
+        /// Days of the year, in calendar order.
+        pub(crate) static DAYS: &[$crate::Entry] = &[$($crate::Entry {
+            number: <$module::$solver as $crate::Solution>::DAY,
+            title: <$module::$solver as $crate::Solution>::TITLE,
+            parts: <$module::$solver as $crate::Solution>::PARTS,
+            solver: || Box::new($module::$solver::default()),
+            source: include_str!(concat!(stringify!($module), ".rs")),
+        },)*];
+    };
+}
+
+/// Declare the years: their modules, each holding a `days!` list, and
+/// [`Year`] variants.
+macro_rules! years {
+    ($($module:ident => $year:ident = $number:literal),* $(,)?) => {
+        $(mod $module;)*
+
+        #[derive(Clone, Copy, Debug, EnumIter, Eq, FromRepr, Hash, Ord, PartialEq, PartialOrd)]
+        #[repr(u16)]
+        pub enum Year {
+            $($year = $number,)*
+        }
+
+        impl Year {
+            fn entries(&self) -> &'static [Entry] {
+                match self {
+                    $(Year::$year => $module::DAYS,)*
+                }
+            }
+        }
+    };
+}
+
+years! {
+    y2025 => Y2025 = 2025,
+}
+
+impl Year {
+    /// The first year, whose inputs and settings may also be stored
+    /// without a year, as they were before there were several.
+    pub fn first() -> Year {
+        Year::iter().next().expect("at least one year")
+    }
+
+    pub fn latest() -> Year {
+        Year::iter().next_back().expect("at least one year")
+    }
+
+    /// Day `number` of the year, if it has a solver.
+    pub fn day(&self, number: u8) -> Option<Day> {
+        self.entries()
+            .iter()
+            .any(|e| e.number == number)
+            .then_some(Day {
+                year: *self,
+                number,
+            })
+    }
+
+    /// Days of the year having a solver, in calendar order.
+    pub fn days(&self) -> impl Iterator<Item = Day> {
+        self.entries().iter().map(|e| Day {
+            year: *self,
+            number: e.number,
+        })
+    }
+
+    /// Directory of the year's inputs in `dir`: `dir/<year>`, or `dir`
+    /// itself for the first year when there is no such directory.
+    pub fn input_dir(&self, dir: &Path) -> PathBuf {
+        let year_dir = dir.join(self.to_string());
+        match year_dir.is_dir() || *self != Year::first() {
+            true => year_dir,
+            false => dir.to_path_buf(),
+        }
+    }
+}
+
+impl fmt::Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", *self as u16)
+    }
+}
+
+impl FromStr for Year {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let number = s
+            .trim()
+            .parse()
+            .map_err(|_| anyhow!("invalid year '{}'", s.trim()))?;
+        Year::from_repr(number).ok_or_else(|| {
+            Failure::UnknownDay.error(format!(
+                "no days for {number}, years are {}",
+                Year::iter().join(", ")
+            ))
+        })
+    }
+}
+
+/// A day of a year having a solver.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Day {
+    year: Year,
+    number: u8,
+}
+
+impl Day {
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    /// Number of the day in the calendar.
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    fn entry(&self) -> &'static Entry {
+        self.year
+            .entries()
+            .iter()
+            .find(|e| e.number == self.number)
+            .expect("days are taken from the registry")
+    }
+
+    pub fn title(&self) -> &'static str {
+        self.entry().title
+    }
+
+    pub fn parts(&self) -> &'static [u8] {
+        self.entry().parts
+    }
+
+    /// Whether the day has a part `part` to run.
+    pub fn has_part(&self, part: u8) -> bool {
+        self.parts().contains(&part)
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day{:02}", self.number)
+    }
+}
+
+impl From<Day> for Box<dyn AocRun> {
+    fn from(value: Day) -> Self {
+        (value.entry().solver)()
+    }
+}
+
+/// Count of the parts that were run and of those that failed, either with
+/// an error, a panic or a wrong answer.
+#[derive(Debug, Default)]
+pub struct RunSummary {
+    pub parts: usize,
+    pub failed: usize,
+    /// Failure with the highest exit code.
+    pub worst: Option<Failure>,
+}
+
+impl RunSummary {
+    pub fn merge(&mut self, other: RunSummary) {
+        self.parts += other.parts;
+        self.failed += other.failed;
+        self.worst = self.worst.max(other.worst);
+    }
+
+    pub fn exit_code(&self) -> u8 {
+        self.worst.map_or(0, |f| f.exit_code())
+    }
+}
+
+/// Run the selected `days` as described by `options` and hand the results
+/// to `reporter`.
+pub fn run(
+    days: &DaySelection,
+    options: &RunOptions,
+    reporter: &mut dyn Reporter,
+) -> Result<RunSummary> {
+    let mut tally = Tally::new(reporter);
+    let reporter = &mut tally;
+    if options.input.is_some() && days.days().len() > 1 {
+        bail!("an input can only be given for a single day");
+    }
+    let modules = days
+        .days()
+        .iter()
+        .map(|day| Ok((*day, options.module(day)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    reporter.begin(&days.to_string());
+    match modules.as_slice() {
+        modules if options.jobs > 1 => parallel::run_days(modules, options, reporter),
+        [(day, module)] => module.run(day, &options.input_for(day), options, reporter)?,
+        modules => {
+            let start = Instant::now();
+            let cpu = timing::cpu_time();
+            for (day, module) in modules {
+                let day_start = Instant::now();
+                module.run(day, &options.input_for(day), options, reporter)?;
+                reporter.day_done(day, day_start.elapsed());
+            }
+            reporter.summary(start.elapsed(), timing::cpu_time() - cpu);
+        }
+    }
+    reporter.end();
+    Ok(tally.summary)
+}
+
+/// Answer of `part` of `day`, run as described by `options`.
+pub fn solve(day: &Day, part: u8, options: &RunOptions) -> Result<Answer> {
+    let module = options.module(day)?;
+    let mut loaded = module.load(&options.input_for(day));
+    let record = module.record_part(day, part, &mut loaded, options);
+    match (record.answer, record.error, record.failure) {
+        (Some(answer), _, _) => Ok(answer),
+        (None, error, failure) => {
+            let error = error.unwrap_or_else(|| "no answer".to_string());
+            Err(failure.unwrap_or(Failure::Solver).error(error))
+        }
+    }
+}
+
+/// Lines of `input`.
+pub fn lines(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+/// First line of `input`, which must have one.
+pub fn single_line(input: &str) -> Result<String> {
+    match input.lines().next() {
+        Some(line) => Ok(line.to_string()),
+        None => Err(Failure::Parse.error("empty input")),
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    pub fn load_test_input(input: &str) -> Vec<String> {
-        let pouet = input
-            .lines()
-            .skip_while(|&l| l.is_empty())
-            .map(|l| l.to_string())
-            .collect();
-        println!("{:?}", pouet);
-        pouet
-    }
-
-    pub fn load_test_input_single_line(input: &str) -> String {
-        input.lines().map(|l| l.to_string()).next().unwrap()
+    #[test_log::test]
+    fn test_registry() {
+        use crate::*;
+
+        let year = Year::Y2025;
+        assert!(year.days().zip(1..).all(|(day, n)| day.number() == n));
+        assert!(year.days().all(|day| day.year() == year));
+        let day = year.day(8).unwrap();
+        assert!(day.title() == "Playground");
+        assert!(day.to_string() == "Day08");
+        assert!(year.day(1).unwrap().has_part(2));
+        assert!(!year.day(12).unwrap().has_part(2));
+        assert!(year.day(26).is_none());
+        assert!("2025".parse::<Year>().unwrap() == year);
+        assert!("1999".parse::<Year>().is_err());
     }
 }
@@ -1,33 +1,439 @@
-use anyhow::Result;
-use clap::Parser;
+use std::{
+    io::{self, IsTerminal},
+    path::PathBuf,
+    process::ExitCode,
+    sync::Arc,
+    time::Duration,
+};
+
+use adventofcode2025::{
+    InputSource, Parts, RunOptions, Year,
+    answers::Answers,
+    cache::ResultCache,
+    client::{self, Client, Outcome, Submissions},
+    config::Config,
+    dashboard::{self, Logs},
+    error::Failure,
+    examples,
+    history::{self, History},
+    profiles, repl,
+    report::Format,
+    scaffold,
+    selection::DaySelection,
+    watch,
+};
+use anyhow::{Result, anyhow};
+use clap::{Parser, Subcommand};
 use tracing::level_filters::LevelFilter;
-use tracing_subscriber::{EnvFilter, layer::SubscriberExt};
+use tracing_subscriber::{EnvFilter, fmt::writer::BoxMakeWriter, layer::SubscriberExt};
 
 #[derive(Debug, Parser)]
+#[command(
+    after_help = "Exit codes: 0 ok, 1 error, 2 usage, 3 unknown day, 4 missing input, \
+                        5 parse error, 6 solver error, 7 answer mismatch, 8 panic \
+                        (the highest one wins)"
+)]
 struct Cli {
     /// log level
     #[arg(long = "log")]
     #[arg(env = "AOC_LOG")]
-    #[arg(default_value = "info")]
-    pub log_level: String,
+    pub log_level: Option<String>,
+
+    #[clap(long, global = true)]
+    /// Config file, `aoc.toml` in the current directory or a parent by default
+    config: Option<PathBuf>,
+
+    #[clap(short, long, global = true)]
+    /// Year of the days to run, the latest one by default
+    year: Option<u16>,
+
+    #[clap(short, long, global = true)]
+    /// Day(s) to run, e.g. 8, 1,4,9, 3..7 or 5- (all days by default)
+    day: Option<String>,
+
+    #[clap(short, long, global = true)]
+    /// Puzzle input file, or `-` to read it from stdin
+    input: Option<InputSource>,
+
+    #[clap(long, global = true, conflicts_with = "input")]
+    /// Run on the input set of a profile, stored as inputs/<profile>/<year>/dayNN.txt
+    profile: Option<String>,
+
+    #[clap(long, conflicts_with_all = ["input", "profile", "answers"])]
+    /// Run on every profile's input set and show a days × profiles matrix
+    all_profiles: bool,
+
+    #[clap(long, conflicts_with_all = ["input", "profile", "all_profiles", "answers"])]
+    /// Run on the example fixtures of the puzzle statements, as embedded
+    /// from `examples/` when building, and check their answers
+    example: bool,
+
+    #[clap(short, long, global = true)]
+    /// Part(s) to run: 1, 2 or both
+    part: Option<Parts>,
 
     #[clap(short, long)]
-    /// Index of the day
-    day: Option<u8>,
+    /// Output format: pretty, plain, json, csv or markdown
+    format: Option<Format>,
+
+    #[clap(long, global = true)]
+    /// Compare the answers against the expected ones
+    check: bool,
+
+    #[clap(long, global = true, conflicts_with = "profile")]
+    /// Expected answers file used by --check, profiles having their own
+    answers: Option<PathBuf>,
+
+    #[clap(short, long, global = true, default_value_t = 1)]
+    /// Number of days to run in parallel
+    jobs: usize,
+
+    #[clap(long, global = true)]
+    /// With --jobs, also run the parts of a day in parallel
+    split_parts: bool,
+
+    #[clap(long, global = true)]
+    /// Take the answers of the parts solved before from the result cache
+    cached: bool,
+
+    #[clap(long, global = true, conflicts_with = "cached")]
+    /// Neither read nor store answers in the result cache
+    no_cache: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// List the selected days with their titles
+    List,
+    /// Generate the module, input and example files of a new day
+    New {
+        #[clap(value_name = "DAY")]
+        /// Number of the day
+        number: u8,
+
+        #[clap(long, default_value = "TBD")]
+        /// Title of the puzzle
+        title: String,
+    },
+    /// Download the puzzle input of a day into the input directory
+    Fetch {
+        #[clap(value_name = "DAY")]
+        /// Number of the day
+        number: u8,
+
+        #[clap(long)]
+        /// Download it again even if it is already there
+        force: bool,
+    },
+    /// Solve a part of a day and submit its answer
+    Submit {
+        #[clap(value_name = "DAY")]
+        /// Number of the day
+        number: u8,
+
+        #[clap(value_name = "PART")]
+        /// Part to submit, 1 or 2
+        level: u8,
+
+        #[clap(long)]
+        /// Answer to submit instead of solving the part
+        answer: Option<String>,
+    },
+    /// Show how the answers and runtimes of the selected days changed over
+    /// the runs, flagging answers that changed for the same input
+    History,
+    /// Run the selected days on their inputs and example fixtures, and again
+    /// whenever one of these files changes
+    Watch {
+        #[clap(long, default_value_t = 500)]
+        /// Milliseconds between checks for changes
+        interval: u64,
+    },
+    /// Show the status of every part of the selected days in a full screen
+    /// dashboard, running them one at a time in the background. Running the
+    /// whole calendar from a terminal does so unless a format or several
+    /// jobs are given
+    Dashboard,
+    /// Open a shell to solve and query the parsed input of a day
+    Repl,
+    /// Manage the result cache
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Run the selected days repeatedly and report timing statistics
+    Bench {
+        #[clap(short = 'n', long, default_value_t = 10)]
+        /// Number of measured runs
+        runs: usize,
+
+        #[clap(short, long, default_value_t = 1)]
+        /// Number of unmeasured runs before measuring
+        warmup: usize,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum CacheAction {
+    /// Remove all the stored answers
+    Clear,
 }
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("Error: {e:?}");
+            ExitCode::from(Failure::find(&e).map_or(1, |f| f.exit_code()))
+        }
+    }
+}
+
+fn try_main() -> Result<ExitCode> {
     let args = Cli::parse();
-    setup_logging(&args.log_level)?;
-    adventofcode2025::run(args.day.unwrap_or_default())
+    let config = match &args.config {
+        Some(path) => Config::load(path)?,
+        None => Config::find()?.unwrap_or_default(),
+    };
+    // the dashboard shows the logs of each day rather than having them
+    // written over it
+    let logs = uses_dashboard(&args, &config).then(Logs::default);
+    setup_logging(
+        args.log_level
+            .as_deref()
+            .or(config.log.as_deref())
+            .unwrap_or("info"),
+        logs.clone(),
+    )?;
+    if let Some(Command::New { number, title }) = &args.command {
+        let year = match args.year {
+            Some(year) => year,
+            None => config.year()? as u16,
+        };
+        // a new year has no directory to fall back on
+        let input_dir = match Year::from_repr(year) {
+            Some(year) => year.input_dir(&config.input_dir()),
+            None => config.input_dir().join(year.to_string()),
+        };
+        let root = scaffold::find_root()?;
+        for path in scaffold::new_day(&root, &input_dir, year, *number, title)? {
+            println!("created {}", path.display());
+        }
+        println!("registered day{number:02} of {year}");
+        return Ok(ExitCode::SUCCESS);
+    }
+    let year = match args.year {
+        Some(year) => year.to_string().parse()?,
+        None => config.year()?,
+    };
+    let days = match &args.day {
+        Some(day) => DaySelection::parse(year, day)?,
+        None => DaySelection::all(year),
+    };
+    let format = match (args.format, &config.format) {
+        (Some(format), _) => format,
+        (None, Some(format)) => format
+            .parse()
+            .map_err(|_| anyhow!("invalid format '{format}' in config"))?,
+        (None, None) => Format::default(),
+    };
+    let options = RunOptions {
+        input: args.input,
+        input_dir: config.input_dir(),
+        params: config.params()?,
+        parts: match (args.part, &config.parts) {
+            (Some(parts), _) => parts,
+            (None, Some(parts)) => parts
+                .parse()
+                .map_err(|_| anyhow!("invalid parts '{parts}' in config"))?,
+            (None, None) => Parts::default(),
+        },
+        answers: None,
+        jobs: args.jobs,
+        split_parts: args.split_parts,
+        profile: None,
+        cache: match args.no_cache {
+            true => None,
+            false => Some(Arc::new(ResultCache::load(&config.results()))),
+        },
+        cached: args.cached,
+        history: Some(Arc::new(History::new(&config.history()))),
+    };
+    let answers = match args.check {
+        true => Some(args.answers.unwrap_or(config.answers())),
+        false => None,
+    };
+    let options = match (&args.profile, &answers) {
+        (Some(profile), _) => {
+            profiles::options_for(&options, &config.profiles_dir(), profile, args.check)?
+        }
+        (None, Some(answers)) if !args.all_profiles && !args.example => RunOptions {
+            answers: Some(Answers::load(answers)?),
+            ..options
+        },
+        (None, _) => options,
+    };
+    match args.command {
+        Some(Command::New { .. }) => unreachable!("handled above"),
+        Some(Command::List) => {
+            for day in days.days() {
+                match day.parts() {
+                    [part] => println!("{day}  {} (part {part} only)", day.title()),
+                    _ => println!("{day}  {}", day.title()),
+                }
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Fetch { number, force }) => {
+            if !(1..=25).contains(&number) {
+                return Err(Failure::UnknownDay.error(format!("day {number} is out of range 1-25")));
+            }
+            let client = Client::new(config.base_url(), &config.session()?);
+            let dir = year.input_dir(&options.input_dir);
+            match client::fetch(&client, year, number, &dir, force)? {
+                (path, true) => println!("fetched {}", path.display()),
+                (path, false) => println!("{} is already there", path.display()),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Submit {
+            number,
+            level: part,
+            answer,
+        }) => {
+            let day = year
+                .day(number)
+                .ok_or_else(|| Failure::UnknownDay.error(format!("{year} has no day {number}")))?;
+            if !day.has_part(part) {
+                return Err(Failure::UnknownDay.error(format!("{day} has no part {part}")));
+            }
+            let answer = match answer {
+                Some(answer) => answer,
+                None => adventofcode2025::solve(&day, part, &options)?.to_string(),
+            };
+            let client = Client::new(config.base_url(), &config.session()?);
+            let mut submissions = Submissions::load(&config.submissions())?;
+            let outcome = submissions.submit(&client, year, number, part, &answer)?;
+            println!("{day} part {part}: {answer}, {outcome}");
+            Ok(match outcome {
+                Outcome::Right | Outcome::WrongLevel => ExitCode::SUCCESS,
+                Outcome::Wrong { .. } => ExitCode::from(Failure::Mismatch.exit_code()),
+                Outcome::TooSoon { .. } => ExitCode::FAILURE,
+            })
+        }
+        Some(Command::History) => {
+            let entries = History::load(&config.history())?;
+            match history::show(&entries, &days) {
+                0 => {}
+                1 => println!("1 answer changed for the same input"),
+                n => println!("{n} answers changed for the same input"),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Watch { interval }) => {
+            let examples = scaffold::find_root().ok().map(|root| root.join("examples"));
+            watch::watch(
+                &days,
+                &options,
+                examples.as_deref(),
+                format,
+                Duration::from_millis(interval),
+            )?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Dashboard) => {
+            let summary = dashboard::run(&days, &options, logs.unwrap_or_default())?;
+            Ok(ExitCode::from(summary.exit_code()))
+        }
+        Some(Command::Repl) => {
+            let [day] = days.days() else {
+                return Err(anyhow!("the repl runs a single day, pick it with --day"));
+            };
+            if matches!(options.input_for(day), InputSource::Stdin) {
+                return Err(anyhow!(
+                    "the repl reads its commands from stdin, not the input"
+                ));
+            }
+            repl::run(day, &options, std::io::stdin().lock(), std::io::stdout())?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Cache {
+            action: CacheAction::Clear,
+        }) => {
+            let path = config.results();
+            match ResultCache::clear(&path)? {
+                true => println!("removed {}", path.display()),
+                false => println!("no result cache in {}", path.display()),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Bench { runs, warmup }) => {
+            adventofcode2025::bench::bench(&days, &options, runs, warmup)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        None if args.example => {
+            let fixtures = examples::Fixture::embedded()?;
+            let mut reporter = format.reporter();
+            let summary = examples::run(&days, &options, &fixtures, reporter.as_mut())?;
+            Ok(ExitCode::from(summary.exit_code()))
+        }
+        None if args.all_profiles => {
+            let dir = config.profiles_dir();
+            let profiles = profiles::discover(&dir)?;
+            let summary = profiles::run_all(&days, &options, &dir, &profiles, args.check, format)?;
+            Ok(ExitCode::from(summary.exit_code()))
+        }
+        None if logs.is_some() => {
+            let summary = dashboard::run(&days, &options, logs.unwrap_or_default())?;
+            Ok(ExitCode::from(summary.exit_code()))
+        }
+        None => {
+            let mut reporter = format.reporter();
+            let summary = adventofcode2025::run(&days, &options, reporter.as_mut())?;
+            Ok(ExitCode::from(summary.exit_code()))
+        }
+    }
+}
+
+/// Whether to show the dashboard: when asked to, or in place of the spinner
+/// lines when running the whole calendar from a terminal. The dashboard runs
+/// the days serially, so it is not used when asked for parallel jobs.
+fn uses_dashboard(args: &Cli, config: &Config) -> bool {
+    match args.command {
+        Some(Command::Dashboard) => true,
+        Some(_) => false,
+        None => {
+            args.day.is_none()
+                && args.input.is_none()
+                && args.format.is_none()
+                && config.format.is_none()
+                && !args.example
+                && !args.all_profiles
+                && args.jobs <= 1
+                && !args.split_parts
+                && io::stdin().is_terminal()
+                && io::stdout().is_terminal()
+        }
+    }
 }
 
-fn setup_logging(log_level: &str) -> Result<()> {
+fn setup_logging(log_level: &str, logs: Option<Logs>) -> Result<()> {
     let filter = EnvFilter::builder()
         .with_default_directive(LevelFilter::DEBUG.into())
         .from_env()?
         .add_directive(log_level.parse()?);
-    let layer = tracing_subscriber::fmt::layer().without_time();
+    let ansi = logs.is_none();
+    let writer = match logs {
+        Some(logs) => BoxMakeWriter::new(logs),
+        None => BoxMakeWriter::new(std::io::stderr),
+    };
+    let layer = tracing_subscriber::fmt::layer()
+        .without_time()
+        .with_ansi(ansi)
+        .with_writer(writer);
     let tracer = tracing_subscriber::registry().with(layer).with(filter);
     tracing::subscriber::set_global_default(tracer)?;
     Ok(())
@@ -0,0 +1,70 @@
+use std::{
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Install a panic hook that keeps quiet about the panics caught by
+/// [`catch`] and lets every other panic through to the default hook.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(|c| c.get()) {
+                default(info);
+                return;
+            }
+            let message = match info.payload().downcast_ref::<&str>() {
+                Some(s) => s.to_string(),
+                None => match info.payload().downcast_ref::<String>() {
+                    Some(s) => s.clone(),
+                    None => "Box<dyn Any>".to_string(),
+                },
+            };
+            let message = match info.location() {
+                Some(location) => format!("panicked at {location}: {message}"),
+                None => format!("panicked: {message}"),
+            };
+            LAST_PANIC.with(|p| *p.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+/// Run `f`, turning a panic into an error message holding the panic
+/// message and its location.
+pub(crate) fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    install_hook();
+    let catching = CATCHING.with(|c| c.replace(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|c| c.set(catching));
+    res.map_err(|_| {
+        LAST_PANIC
+            .with(|p| p.borrow_mut().take())
+            .unwrap_or_else(|| "panicked".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::panics::*;
+
+    #[test_log::test]
+    fn test_catch() {
+        assert!(catch(|| 42) == Ok(42));
+
+        let line = line!() + 1;
+        let res: Result<(), String> = catch(|| panic!("wrong input"));
+        let err = res.unwrap_err();
+        assert!(err.starts_with(&format!("panicked at {}:{}:", file!(), line)));
+        assert!(err.ends_with(": wrong input"));
+
+        let res: Result<i32, String> = catch(|| "x".parse::<i32>().unwrap());
+        assert!(res.unwrap_err().contains("InvalidDigit"));
+    }
+}
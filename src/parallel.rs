@@ -0,0 +1,177 @@
+use std::{
+    collections::BTreeMap,
+    ops::Range,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
+
+use crate::{AocRun, Day, RunOptions, report::Record, report::Reporter, timing::cpu_time};
+
+/// A unit of work for the pool: some parts of a day, run in sequence.
+struct Task<'a> {
+    day: Day,
+    module: &'a dyn AocRun,
+    parts: Vec<u8>,
+}
+
+struct Done {
+    task: usize,
+    records: Vec<Record>,
+    start: Instant,
+    end: Instant,
+}
+
+/// Run `days` on `options.jobs` worker threads.
+///
+/// Results are handed to `reporter` in day order, as soon as a day and all
+/// the days before it are complete.
+pub(crate) fn run_days(
+    days: &[(Day, Box<dyn AocRun>)],
+    options: &RunOptions,
+    reporter: &mut dyn Reporter,
+) {
+    let start = Instant::now();
+    let cpu = cpu_time();
+
+    let mut tasks: Vec<Task> = vec![];
+    let mut ranges: Vec<Range<usize>> = vec![];
+    for (day, module) in days {
+        let (day, module) = (*day, module.as_ref());
+        let parts: Vec<u8> = (1..=2).filter(|&p| options.runs(&day, p)).collect();
+        let first = tasks.len();
+        if options.split_parts {
+            tasks.extend(parts.iter().map(|&part| Task {
+                day,
+                module,
+                parts: vec![part],
+            }));
+        } else {
+            tasks.push(Task {
+                day,
+                module,
+                parts: parts.clone(),
+            });
+        }
+        ranges.push(first..tasks.len());
+    }
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.clamp(1, tasks.len().max(1)) {
+            let tx = tx.clone();
+            let (next, tasks) = (&next, &tasks);
+            scope.spawn(move || {
+                loop {
+                    let idx = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(task) = tasks.get(idx) else {
+                        break;
+                    };
+                    let input = options.input_for(&task.day);
+                    let start = Instant::now();
+                    let mut loaded = None;
+                    let hash = options.input_hash(&input);
+                    let records = task
+                        .parts
+                        .iter()
+                        .map(|&part| {
+                            task.module.run_part(
+                                &task.day,
+                                part,
+                                &input,
+                                &mut loaded,
+                                hash.as_deref(),
+                                options,
+                            )
+                        })
+                        .collect();
+                    let done = Done {
+                        task: idx,
+                        records,
+                        start,
+                        end: Instant::now(),
+                    };
+                    if tx.send(done).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending: BTreeMap<usize, Done> = BTreeMap::new();
+        let mut emitted = 0;
+        for done in rx {
+            pending.insert(done.task, done);
+            while emitted < days.len() && ranges[emitted].clone().all(|t| pending.contains_key(&t))
+            {
+                let done: Vec<Done> = ranges[emitted]
+                    .clone()
+                    .filter_map(|t| pending.remove(&t))
+                    .collect();
+                emit(&days[emitted].0, done, options, reporter);
+                emitted += 1;
+            }
+        }
+    });
+
+    reporter.summary(start.elapsed(), cpu_time() - cpu);
+}
+
+fn emit(day: &Day, done: Vec<Done>, options: &RunOptions, reporter: &mut dyn Reporter) {
+    let start = done.iter().map(|d| d.start).min();
+    let end = done.iter().map(|d| d.end).max();
+    let mut records: Vec<Record> = done.into_iter().flat_map(|d| d.records).collect();
+
+    reporter.day(day);
+    for part in 1..=2 {
+        if !options.runs(day, part) {
+            reporter.part_skipped(day, part);
+            continue;
+        }
+        if let Some(pos) = records.iter().position(|r| r.part == part) {
+            reporter.part_started(day, part);
+            reporter.record(records.remove(pos));
+        }
+    }
+    if let (Some(start), Some(end)) = (start, end) {
+        reporter.day_done(day, end - start);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Parts, Year, parallel::*};
+
+    #[derive(Default)]
+    struct Collect(Vec<(u8, u8)>);
+
+    impl Reporter for Collect {
+        fn record(&mut self, record: Record) {
+            self.0.push((record.day, record.part));
+        }
+    }
+
+    #[test_log::test]
+    fn test_ordered_output() {
+        let options = RunOptions {
+            parts: Parts::Both,
+            jobs: 3,
+            split_parts: true,
+            ..Default::default()
+        };
+        let mut reporter = Collect::default();
+        let days: Vec<_> = [3, 1, 5]
+            .into_iter()
+            .map(|n| Year::Y2025.day(n).unwrap())
+            .map(|day| (day, options.module(&day).unwrap()))
+            .collect();
+        run_days(&days, &options, &mut reporter);
+        assert!(reporter.0 == vec![(3, 1), (3, 2), (1, 1), (1, 2), (5, 1), (5, 2)]);
+    }
+}
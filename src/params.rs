@@ -0,0 +1,60 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use anyhow::{Result, anyhow};
+
+/// Named settings tweaking how a day solves its puzzle, such as the number
+/// of connections made by day 8.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    pub fn get<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.0
+            .get(key)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| anyhow!("invalid value '{value}' for {key}: {e}"))
+            })
+            .transpose()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Params {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Params(
+            iter.into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::*;
+
+    #[test_log::test]
+    fn test_get() {
+        let params: Params = [("connections", "10"), ("name", "x")].into_iter().collect();
+        assert!(params.get::<i32>("connections").unwrap() == Some(10));
+        assert!(params.get::<i32>("missing").unwrap().is_none());
+        assert!(params.get::<i32>("name").is_err());
+    }
+}
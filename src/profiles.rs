@@ -0,0 +1,221 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+
+use crate::{
+    RunOptions, RunSummary,
+    answers::Answers,
+    report::{Format, Record, Reporter},
+    selection::DaySelection,
+    timing::format_duration,
+};
+
+/// Names of the input sets found in `dir`, one sub-directory per profile as
+/// in `inputs/<profile>/<year>/dayNN.txt`.
+pub fn discover(dir: &Path) -> Result<Vec<String>> {
+    let mut profiles = vec![];
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("failed to list profiles in {}", dir.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            profiles.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+    profiles.sort();
+    Ok(profiles)
+}
+
+/// Options to run the input set of `profile` stored in `dir`. When `check`ing
+/// the answers, they are compared with the profile's own `answers.toml`,
+/// every verdict being unknown without one: the answers of other input sets
+/// don't apply.
+pub fn options_for(
+    options: &RunOptions,
+    dir: &Path,
+    profile: &str,
+    check: bool,
+) -> Result<RunOptions> {
+    let input_dir = dir.join(profile);
+    if !input_dir.is_dir() {
+        bail!(
+            "no input set for profile '{}' in {}",
+            profile,
+            dir.display()
+        );
+    }
+    let own_answers = input_dir.join("answers.toml");
+    let answers = match check {
+        true if own_answers.is_file() => Some(Answers::load(&own_answers)?),
+        true => Some(Answers::default()),
+        false => None,
+    };
+    Ok(RunOptions {
+        input_dir,
+        answers,
+        profile: Some(profile.to_string()),
+        ..options.clone()
+    })
+}
+
+#[derive(Default)]
+struct Collect(Vec<Record>);
+
+impl Reporter for Collect {
+    fn record(&mut self, record: Record) {
+        self.0.push(record);
+    }
+}
+
+/// Run `days` once per profile, checking the answers when asked to. Machine
+/// readable formats get every record tagged with its profile, the others a
+/// days × profiles matrix.
+pub fn run_all(
+    days: &DaySelection,
+    options: &RunOptions,
+    dir: &Path,
+    profiles: &[String],
+    check: bool,
+    format: Format,
+) -> Result<RunSummary> {
+    let mut summary = RunSummary::default();
+    let mut columns = vec![];
+    let mut reporter = format.reporter();
+    for profile in profiles {
+        let options = options_for(options, dir, profile, check)?;
+        let res = match format {
+            Format::Json | Format::Csv => crate::run(days, &options, reporter.as_mut())?,
+            _ => {
+                let mut collect = Collect::default();
+                let res = crate::run(days, &options, &mut collect)?;
+                columns.push(collect.0);
+                res
+            }
+        };
+        summary.merge(res);
+    }
+    if !matches!(format, Format::Json | Format::Csv) {
+        print!("{}", render(profiles, &columns, format == Format::Markdown));
+    }
+    Ok(summary)
+}
+
+fn cell(record: Option<&Record>) -> String {
+    let Some(record) = record else {
+        return "-".to_string();
+    };
+    let mut cell = match (&record.answer, &record.failure) {
+        (Some(answer), _) => format!("{} ({})", answer, format_duration(record.timings.total())),
+        (None, Some(failure)) => format!("✖ {failure}"),
+        (None, None) => "✖".to_string(),
+    };
+    if let Some(verdict) = &record.verdict {
+        cell.push_str(&format!(" [{verdict}]"));
+    }
+    cell
+}
+
+/// Lay out one row per day and part, one column per profile.
+fn render(profiles: &[String], columns: &[Vec<Record>], markdown: bool) -> String {
+    let mut keys: Vec<(u8, u8)> = columns.iter().flatten().map(|r| (r.day, r.part)).collect();
+    keys.sort();
+    keys.dedup();
+
+    let mut rows: Vec<Vec<String>> = vec![];
+    let mut header = vec!["Day".to_string(), "Part".to_string()];
+    header.extend(profiles.iter().cloned());
+    rows.push(header);
+    for (day, part) in keys {
+        let mut row = vec![day.to_string(), part.to_string()];
+        row.extend(
+            columns
+                .iter()
+                .map(|records| cell(records.iter().find(|r| r.day == day && r.part == part))),
+        );
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|i| rows.iter().map(|r| r[i].chars().count()).max().unwrap_or(0))
+        .collect();
+    let mut out = String::new();
+    for (n, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell.replace('|', "\\|")))
+            .collect();
+        match markdown {
+            true => out.push_str(&format!("| {} |\n", cells.join(" | "))),
+            false => out.push_str(&format!("{}\n", cells.join("  ").trim_end())),
+        }
+        if markdown && n == 0 {
+            let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+            out.push_str(&format!("| {} |\n", rule.join(" | ")));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Answer, Year, answers::Verdict, profiles::*, timing::Timings};
+
+    fn record(day: u8, part: u8, answer: Option<i64>) -> Record {
+        Record {
+            year: 2025,
+            day,
+            part,
+            answer: answer.map(Answer::from),
+            timings: Timings::default(),
+            error: None,
+            failure: None,
+            verdict: None,
+            profile: None,
+            cached: false,
+            input: None,
+        }
+    }
+
+    #[test_log::test]
+    fn test_options_for() {
+        let dir = std::env::temp_dir().join(format!("aoc-profiles-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        fs::create_dir_all(dir.join("bob")).unwrap();
+        fs::write(dir.join("bob/answers.toml"), "[day01]\npart1 = 3\n").unwrap();
+        let day = Year::Y2025.day(1).unwrap();
+        let options = RunOptions::default();
+
+        let alice = options_for(&options, &dir, "alice", true).unwrap();
+        let verdict = alice.answers.unwrap().check(&day, 1, Some("3"));
+        assert!(verdict == Verdict::Unknown);
+        let bob = options_for(&options, &dir, "bob", true).unwrap();
+        assert!(bob.answers.unwrap().check(&day, 1, Some("3")) == Verdict::Pass);
+        assert!(
+            options_for(&options, &dir, "bob", false)
+                .unwrap()
+                .answers
+                .is_none()
+        );
+        assert!(options_for(&options, &dir, "carol", true).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test_log::test]
+    fn test_render() {
+        let profiles = vec!["alice".to_string(), "bob".to_string()];
+        let columns = vec![
+            vec![record(1, 1, Some(3)), record(1, 2, Some(6))],
+            vec![record(1, 1, Some(42))],
+        ];
+        let out = render(&profiles, &columns, false);
+        let lines: Vec<&str> = out.lines().collect();
+        assert!(lines[0] == "Day  Part  alice     bob");
+        assert!(lines[1] == "1    1     3 (0 ns)  42 (0 ns)");
+        assert!(lines[2] == "1    2     6 (0 ns)  -");
+
+        let out = render(&profiles, &columns, true);
+        assert!(out.lines().nth(1).unwrap() == "| --- | ---- | -------- | --------- |");
+    }
+}
@@ -0,0 +1,234 @@
+use std::io::{BufRead, Write};
+
+use anyhow::Result;
+
+use crate::{
+    Answer, AocRun, Day, InputSource, Parsed, RunOptions, panics,
+    params::Params,
+    timing::{self, format_duration},
+};
+
+/// Commands every day understands, along with what they do.
+const BUILTINS: &[(&str, &str)] = &[
+    ("part1", "solve part 1"),
+    ("part2", "solve part 2"),
+    (
+        "set KEY VALUE",
+        "set a parameter, such as `set connections 50`",
+    ),
+    ("params", "show the parameters"),
+    ("reload", "read and parse the input again"),
+    ("help", "show this help"),
+    ("quit", "leave"),
+];
+
+/// Shell exploring the input of a day, read and parsed once and then
+/// solved and queried as many times as asked.
+pub struct Repl {
+    day: Day,
+    module: Box<dyn AocRun>,
+    params: Params,
+    input: InputSource,
+    parsed: Result<Parsed, String>,
+}
+
+impl Repl {
+    /// Shell for `day` on its input from `options`, along with how loading
+    /// the input went.
+    pub fn new(day: &Day, options: &RunOptions) -> Result<(Self, String)> {
+        let mut repl = Repl {
+            day: *day,
+            module: options.module(day)?,
+            params: options.params.get(day).cloned().unwrap_or_default(),
+            input: options.input_for(day),
+            parsed: Err("not loaded".to_string()),
+        };
+        let loaded = repl.load();
+        Ok((repl, loaded))
+    }
+
+    fn load(&mut self) -> String {
+        let loaded = self.module.load(&self.input);
+        let timings = loaded.timings.unwrap_or_default();
+        match loaded.input {
+            Ok(parsed) => {
+                self.parsed = Ok(parsed);
+                format!(
+                    "{} loaded in {} (read {}, parse {})",
+                    self.day,
+                    format_duration(timings.total()),
+                    format_duration(timings.read),
+                    format_duration(timings.parse)
+                )
+            }
+            Err((error, _)) => {
+                self.parsed = Err(error.clone());
+                format!("error: {error}")
+            }
+        }
+    }
+
+    /// Run `f` on the parsed input, showing its answer and how long it took.
+    fn timed(&self, f: impl FnOnce(&Parsed) -> Result<Answer>) -> String {
+        let parsed = match &self.parsed {
+            Ok(parsed) => parsed,
+            Err(error) => return format!("error: {error}, fix the input and reload"),
+        };
+        let (res, timings) = timing::timed(|| panics::catch(|| f(parsed)));
+        match res {
+            Ok(Ok(answer)) => format!("{answer} in {}", format_duration(timings.total())),
+            Ok(Err(e)) => format!("error: {e:#}"),
+            Err(panic) => format!("error: {panic}"),
+        }
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> String {
+        let mut params = self.params.clone();
+        params.set(key, value);
+        let mut module: Box<dyn AocRun> = self.day.into();
+        match module.configure(&params) {
+            Ok(()) => {
+                self.module = module;
+                self.params = params;
+                format!("{key} = {value}")
+            }
+            Err(e) => format!("error: {e:#}"),
+        }
+    }
+
+    fn help(&self) -> String {
+        BUILTINS
+            .iter()
+            .chain(self.module.commands())
+            .map(|(usage, help)| format!("  {usage:<16} {help}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Run the command `line`, returning what to show, or `None` when asked
+    /// to leave.
+    pub fn execute(&mut self, line: &str) -> Option<String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let output = match words.as_slice() {
+            [] => String::new(),
+            ["quit" | "exit"] => return None,
+            ["help"] => self.help(),
+            ["part1"] => self.timed(|parsed| self.module.solve(1, parsed)),
+            ["part2"] if self.day.has_part(2) => self.timed(|parsed| self.module.solve(2, parsed)),
+            ["part2"] => format!("error: {} has no part 2", self.day),
+            ["set", key, value] => self.set(key, value),
+            ["params"] => self
+                .params
+                .iter()
+                .map(|(key, value)| format!("{key} = {value}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            ["reload"] => self.load(),
+            [name, args @ ..]
+                if self
+                    .module
+                    .commands()
+                    .iter()
+                    .any(|(usage, _)| usage.split_whitespace().next() == Some(*name)) =>
+            {
+                self.timed(|parsed| self.module.command(parsed, name, args))
+            }
+            [name, ..] => format!("error: unknown command {name}, try help"),
+        };
+        Some(output)
+    }
+}
+
+/// Run a shell for `day`, reading commands from `input` until it ends or
+/// asks to quit.
+pub fn run(
+    day: &Day,
+    options: &RunOptions,
+    input: impl BufRead,
+    mut out: impl Write,
+) -> Result<()> {
+    let (mut repl, loaded) = Repl::new(day, options)?;
+    writeln!(out, "{loaded}\ntype help for the commands")?;
+    let prompt = day.to_string().to_lowercase();
+    write!(out, "{prompt}> ")?;
+    out.flush()?;
+    for line in input.lines() {
+        match repl.execute(&line?) {
+            Some(output) if output.is_empty() => {}
+            Some(output) => writeln!(out, "{output}")?,
+            None => return Ok(()),
+        }
+        write!(out, "{prompt}> ")?;
+        out.flush()?;
+    }
+    writeln!(out)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{Year, repl::*};
+
+    /// Day `number` run on its `name` fixture.
+    fn example(number: u8, name: &str) -> (Day, RunOptions) {
+        let day = Year::Y2025.day(number).unwrap();
+        let input = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!(
+            "examples/2025/{}/{name}.in",
+            day.to_string().to_lowercase()
+        ));
+        let options = RunOptions {
+            input: Some(InputSource::File(input)),
+            ..RunOptions::default()
+        };
+        (day, options)
+    }
+
+    fn repl(number: u8, name: &str) -> Repl {
+        let (day, options) = example(number, name);
+        Repl::new(&day, &options).unwrap().0
+    }
+
+    fn answer(repl: &mut Repl, line: &str) -> String {
+        let output = repl.execute(line).unwrap();
+        output.split(" in ").next().unwrap().to_string()
+    }
+
+    #[test_log::test]
+    fn test_commands() {
+        let mut day05 = repl(5, "example");
+        assert!(answer(&mut day05, "part1") == "3");
+        assert!(answer(&mut day05, "query 17") == "fresh");
+        assert!(answer(&mut day05, "query 8") == "spoiled");
+        assert!(answer(&mut day05, "query") == "error: usage: query ID");
+        assert!(answer(&mut day05, "paths a b").starts_with("error: unknown command"));
+        assert!(day05.execute("quit").is_none());
+
+        let mut day08 = repl(8, "example");
+        assert!(answer(&mut day08, "set connections x").starts_with("error: invalid value"));
+        assert!(answer(&mut day08, "set connections 10") == "connections = 10");
+        assert!(answer(&mut day08, "params") == "connections = 10");
+        assert!(answer(&mut day08, "part1") == "40");
+
+        let mut day11 = repl(11, "you");
+        assert!(answer(&mut day11, "paths you out") == "5");
+        assert!(answer(&mut day11, "paths nowhere out") == "error: no device nowhere");
+    }
+
+    #[test_log::test]
+    fn test_run() {
+        let (day, options) = example(5, "example");
+        let mut out = vec![];
+        run(
+            &day,
+            &options,
+            "part2\n\nquit\npart1\n".as_bytes(),
+            &mut out,
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("day05> 14 in "));
+        assert!(out.ends_with("day05> "));
+    }
+}
@@ -0,0 +1,452 @@
+use std::time::Duration;
+
+use nanospinner::{Spinner, SpinnerHandle};
+use serde_json::json;
+use strum_macros::{Display, EnumString};
+
+use crate::{
+    Answer, Day, RunSummary,
+    answers::Verdict,
+    error::Failure,
+    timing::{Timings, format_duration},
+};
+
+/// Outcome of running one part of a day.
+#[derive(Debug)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub timings: Timings,
+    pub error: Option<String>,
+    /// Why the part failed, if it did.
+    pub failure: Option<Failure>,
+    /// Comparison with the expected answer, when checking.
+    pub verdict: Option<Verdict>,
+    /// Input set the part was run on, if not the default one.
+    pub profile: Option<String>,
+    /// Whether the answer was taken from the result cache.
+    pub cached: bool,
+    /// Hash of the input, when caching or logging the answers.
+    pub input: Option<String>,
+}
+
+impl Record {
+    pub fn failed(&self) -> bool {
+        self.failure.is_some()
+    }
+
+    /// Expected answer, when checking against a known one. A passing part
+    /// expected the answer it gave.
+    fn expected(&self) -> Option<String> {
+        match &self.verdict {
+            Some(Verdict::Fail { expected }) => Some(expected.clone()),
+            Some(Verdict::Pass) => self.answer.as_ref().map(|a| a.to_string()),
+            _ => None,
+        }
+    }
+
+    fn verdict(&self) -> String {
+        self.verdict
+            .as_ref()
+            .map(|v| v.to_string())
+            .unwrap_or_default()
+    }
+
+    /// Verdict to append to a human readable line, if any.
+    fn check_suffix(&self) -> String {
+        match (&self.verdict, self.expected()) {
+            (None, _) => String::new(),
+            (Some(verdict), Some(expected)) => format!(" [{verdict}, expected {expected}]"),
+            (Some(verdict), None) => format!(" [{verdict}]"),
+        }
+    }
+}
+
+/// Receives the results of a run and renders them.
+pub trait Reporter {
+    /// Called once before running `name`, a single day or "all days".
+    fn begin(&mut self, _name: &str) {}
+    fn day(&mut self, _day: &Day) {}
+    fn part_started(&mut self, _day: &Day, _part: u8) {}
+    fn part_skipped(&mut self, _day: &Day, _part: u8) {}
+    fn record(&mut self, record: Record);
+    /// Wall time of a whole day, when running several days.
+    fn day_done(&mut self, _day: &Day, _wall: Duration) {}
+    /// Wall time of the run and CPU time used by the process meanwhile.
+    fn summary(&mut self, _wall: Duration, _cpu: Duration) {}
+    fn end(&mut self) {}
+}
+
+/// Forwards everything to another reporter while counting the failures.
+pub(crate) struct Tally<'a> {
+    inner: &'a mut dyn Reporter,
+    pub summary: RunSummary,
+}
+
+impl<'a> Tally<'a> {
+    pub fn new(inner: &'a mut dyn Reporter) -> Self {
+        Tally {
+            inner,
+            summary: RunSummary::default(),
+        }
+    }
+}
+
+impl Reporter for Tally<'_> {
+    fn begin(&mut self, name: &str) {
+        self.inner.begin(name);
+    }
+    fn day(&mut self, day: &Day) {
+        self.inner.day(day);
+    }
+    fn part_started(&mut self, day: &Day, part: u8) {
+        self.inner.part_started(day, part);
+    }
+    fn part_skipped(&mut self, day: &Day, part: u8) {
+        self.inner.part_skipped(day, part);
+    }
+    fn record(&mut self, record: Record) {
+        self.summary.parts += 1;
+        if record.failed() {
+            self.summary.failed += 1;
+            self.summary.worst = self.summary.worst.max(record.failure);
+        }
+        self.inner.record(record);
+    }
+    fn day_done(&mut self, day: &Day, wall: Duration) {
+        self.inner.day_done(day, wall);
+    }
+    fn summary(&mut self, wall: Duration, cpu: Duration) {
+        self.inner.summary(wall, cpu);
+    }
+    fn end(&mut self) {
+        self.inner.end();
+    }
+}
+
+/// Output format of the results.
+#[derive(Clone, Copy, Debug, Default, Display, EnumString, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum Format {
+    #[default]
+    Pretty,
+    Plain,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl Format {
+    pub fn reporter(&self) -> Box<dyn Reporter> {
+        match self {
+            Format::Pretty => Box::new(Pretty::default()),
+            Format::Plain => Box::new(Plain),
+            Format::Json => Box::new(JsonLines),
+            Format::Csv => Box::new(Csv::default()),
+            Format::Markdown => Box::new(Markdown::default()),
+        }
+    }
+}
+
+/// Spinner lines, the historical output.
+#[derive(Default)]
+struct Pretty {
+    spinner: Option<SpinnerHandle>,
+}
+
+impl Reporter for Pretty {
+    fn begin(&mut self, name: &str) {
+        println!("Running {}", name);
+    }
+
+    fn day(&mut self, day: &Day) {
+        println!("{day}:");
+    }
+
+    fn part_started(&mut self, _day: &Day, part: u8) {
+        self.spinner = Some(Spinner::new(format!(" Running part {part}")).start());
+    }
+
+    fn part_skipped(&mut self, _day: &Day, part: u8) {
+        println!("-  Part {part}: skipped");
+    }
+
+    fn record(&mut self, record: Record) {
+        let Some(spinner) = self.spinner.take() else {
+            return;
+        };
+        let check = record.check_suffix();
+        match (record.answer, &record.error) {
+            (Some(answer), _) if record.cached => {
+                let message = format!(" Part {}: {} (cached){}", record.part, answer, check);
+                match record.verdict {
+                    Some(Verdict::Fail { .. }) => spinner.fail_with(message),
+                    _ => spinner.success_with(message),
+                }
+            }
+            (Some(answer), _) => {
+                let message = format!(
+                    " Part {}: {} in {} (read {}, parse {}, solve {}){}",
+                    record.part,
+                    answer,
+                    format_duration(record.timings.total()),
+                    format_duration(record.timings.read),
+                    format_duration(record.timings.parse),
+                    format_duration(record.timings.solve),
+                    check
+                );
+                match record.verdict {
+                    Some(Verdict::Fail { .. }) => spinner.fail_with(message),
+                    _ => spinner.success_with(message),
+                }
+            }
+            (None, Some(e)) => spinner.fail_with(format!(" {}{}", e, check)),
+            (None, None) => spinner.fail(),
+        }
+    }
+
+    fn day_done(&mut self, day: &Day, wall: Duration) {
+        println!("⏱  {day} in {}", format_duration(wall));
+    }
+
+    fn summary(&mut self, wall: Duration, cpu: Duration) {
+        println!(
+            "⏱  Total: {} wall, {} cpu",
+            format_duration(wall),
+            format_duration(cpu)
+        );
+    }
+}
+
+/// One line per part, without terminal decorations.
+struct Plain;
+
+impl Reporter for Plain {
+    fn part_skipped(&mut self, day: &Day, part: u8) {
+        println!("{day} part {part}: skipped");
+    }
+
+    fn record(&mut self, record: Record) {
+        let day = format!("Day{:02}", record.day);
+        let check = record.check_suffix();
+        match (record.answer, &record.error) {
+            (Some(answer), _) if record.cached => {
+                println!("{day} part {}: {} cached{}", record.part, answer, check)
+            }
+            (Some(answer), _) => println!(
+                "{day} part {}: {} in {} ns (read {} ns, parse {} ns, solve {} ns){}",
+                record.part,
+                answer,
+                record.timings.total().as_nanos(),
+                record.timings.read.as_nanos(),
+                record.timings.parse.as_nanos(),
+                record.timings.solve.as_nanos(),
+                check
+            ),
+            (None, e) => println!(
+                "{day} part {}: failed: {}{}",
+                record.part,
+                e.as_deref().unwrap_or_default(),
+                check
+            ),
+        }
+    }
+
+    fn day_done(&mut self, day: &Day, wall: Duration) {
+        println!("{day} wall: {} ns", wall.as_nanos());
+    }
+
+    fn summary(&mut self, wall: Duration, cpu: Duration) {
+        println!(
+            "total wall: {} ns, cpu: {} ns",
+            wall.as_nanos(),
+            cpu.as_nanos()
+        );
+    }
+}
+
+struct JsonLines;
+
+impl Reporter for JsonLines {
+    fn record(&mut self, record: Record) {
+        let value = json!({
+            "profile": record.profile,
+            "year": record.year,
+            "day": record.day,
+            "part": record.part,
+            "answer": record.answer,
+            "read_ns": record.timings.read.as_nanos() as u64,
+            "parse_ns": record.timings.parse.as_nanos() as u64,
+            "solve_ns": record.timings.solve.as_nanos() as u64,
+            "total_ns": record.timings.total().as_nanos() as u64,
+            "error": record.error,
+            "failure": record.failure.map(|f| f.to_string()),
+            "check": record.verdict.as_ref().map(|v| v.to_string()),
+            "expected": record.expected(),
+            "cached": record.cached,
+            "input": record.input,
+        });
+        println!("{value}");
+    }
+
+    fn day_done(&mut self, day: &Day, wall: Duration) {
+        let value = json!({
+            "year": day.year() as u16,
+            "day": day.number(),
+            "wall_ns": wall.as_nanos() as u64,
+        });
+        println!("{value}");
+    }
+
+    fn summary(&mut self, wall: Duration, cpu: Duration) {
+        let value = json!({
+            "wall_ns": wall.as_nanos() as u64,
+            "cpu_ns": cpu.as_nanos() as u64,
+        });
+        println!("{value}");
+    }
+}
+
+/// Comma separated values, the header being printed once per process.
+#[derive(Default)]
+struct Csv {
+    header: bool,
+}
+
+impl Csv {
+    fn escape(field: &str) -> String {
+        if field.contains([',', '"', '\n']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+}
+
+impl Reporter for Csv {
+    fn begin(&mut self, _name: &str) {
+        if std::mem::replace(&mut self.header, true) {
+            return;
+        }
+        println!(
+            "profile,day,part,answer,read_ns,parse_ns,solve_ns,total_ns,error,check,expected,cached"
+        );
+    }
+
+    fn record(&mut self, record: Record) {
+        println!(
+            "{},{},{},{},{},{},{},{},{},{},{},{}",
+            Csv::escape(record.profile.as_deref().unwrap_or_default()),
+            record.day,
+            record.part,
+            record
+                .answer
+                .as_ref()
+                .map(|a| a.to_string())
+                .unwrap_or_default(),
+            record.timings.read.as_nanos(),
+            record.timings.parse.as_nanos(),
+            record.timings.solve.as_nanos(),
+            record.timings.total().as_nanos(),
+            Csv::escape(record.error.as_deref().unwrap_or_default()),
+            record.verdict(),
+            Csv::escape(&record.expected().unwrap_or_default()),
+            record.cached
+        );
+    }
+}
+
+#[derive(Default)]
+struct Markdown {
+    header: bool,
+}
+
+impl Reporter for Markdown {
+    fn begin(&mut self, _name: &str) {
+        if std::mem::replace(&mut self.header, true) {
+            return;
+        }
+        println!("| Day | Part | Answer | Read | Parse | Solve | Total | Error | Check |");
+        println!("|----:|-----:|-------:|-----:|------:|------:|------:|-------|-------|");
+    }
+
+    fn record(&mut self, record: Record) {
+        let check = match record.expected() {
+            Some(expected) => format!("{} (expected {})", record.verdict(), expected),
+            None => record.verdict(),
+        };
+        let answer = match (&record.answer, record.cached) {
+            (Some(answer), true) => format!("{answer} (cached)"),
+            (answer, _) => answer.as_ref().map(|a| a.to_string()).unwrap_or_default(),
+        };
+        println!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {} |",
+            record.day,
+            record.part,
+            answer,
+            format_duration(record.timings.read),
+            format_duration(record.timings.parse),
+            format_duration(record.timings.solve),
+            format_duration(record.timings.total()),
+            record
+                .error
+                .as_deref()
+                .unwrap_or_default()
+                .replace('|', "\\|")
+                .replace('\n', " "),
+            check
+        );
+    }
+
+    fn summary(&mut self, wall: Duration, cpu: Duration) {
+        println!();
+        println!(
+            "Total: {} wall, {} cpu",
+            format_duration(wall),
+            format_duration(cpu)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+
+    #[test_log::test]
+    fn test_csv_escape() {
+        assert!(Csv::escape("No part 2!") == "No part 2!");
+        assert!(Csv::escape("a, \"b\"") == "\"a, \"\"b\"\"\"");
+    }
+
+    #[test_log::test]
+    fn test_check_suffix() {
+        let record = |answer: i64, verdict| Record {
+            year: 2025,
+            day: 1,
+            part: 1,
+            answer: Some(answer.into()),
+            timings: Timings::default(),
+            error: None,
+            failure: None,
+            verdict,
+            profile: None,
+            cached: false,
+            input: None,
+        };
+        assert!(record(3, None).check_suffix().is_empty());
+        assert!(record(3, Some(Verdict::Pass)).check_suffix() == " [pass, expected 3]");
+        let fail = Verdict::Fail {
+            expected: "3".into(),
+        };
+        assert!(record(4, Some(fail)).check_suffix() == " [fail, expected 3]");
+        assert!(record(4, Some(Verdict::Unknown)).check_suffix() == " [unknown]");
+    }
+
+    #[test_log::test]
+    fn test_format_from_str() {
+        assert!("markdown".parse::<Format>().unwrap() == Format::Markdown);
+        assert!("xml".parse::<Format>().is_err());
+    }
+}
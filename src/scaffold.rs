@@ -0,0 +1,171 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+static TEMPLATE: &str = include_str!("templates/day.rs.tmpl");
+
+static EXPECTED: &str = "# part1 = \n# part2 = \n";
+
+/// Look for the crate holding the days, from the current directory up.
+pub fn find_root() -> Result<PathBuf> {
+    let cwd = env::current_dir()?;
+    cwd.ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file() && dir.join("src/lib.rs").is_file())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| anyhow!("no crate with a src/lib.rs found from {}", cwd.display()))
+}
+
+/// Create the module of day `day` of `year` in the crate at `root` along
+/// with its empty input in `input_dir` and example fixture, and register
+/// it in its year's `days!` list. The year is created and registered in
+/// `src/lib.rs` first if it is new. Returns the files created.
+pub fn new_day(
+    root: &Path,
+    input_dir: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<Vec<PathBuf>> {
+    if day == 0 {
+        bail!("days start at 1");
+    }
+    let name = format!("day{day:02}");
+    let year_dir = root.join("src").join(format!("y{year}"));
+    let module = year_dir.join(format!("{name}.rs"));
+    if module.exists() {
+        bail!("{} already exists", module.display());
+    }
+    let mut created = vec![];
+    let year_module = year_dir.join("mod.rs");
+    if !year_module.exists() {
+        let lib = root.join("src/lib.rs");
+        let entry = format!("y{year} => Y{year} = {year}");
+        update(&lib, |content| register(content, "years!", &entry))?;
+        write(
+            &year_module,
+            &format!("//! Advent of Code {year}.\n\ndays! {{\n}}\n"),
+        )?;
+        created.push(year_module.clone());
+    }
+    let entry = format!("{name}::{}{}", &name[..1].to_uppercase(), &name[1..]);
+    update(&year_module, |content| register(content, "days!", &entry))?;
+
+    let examples = root.join("examples").join(year.to_string()).join(&name);
+    let files = [
+        (module, render(year, day, title)),
+        (input_dir.join(format!("{name}.txt")), String::new()),
+        (examples.join("example.in"), String::new()),
+        (examples.join("example.out"), EXPECTED.to_string()),
+    ];
+    for (path, content) in files {
+        if path.exists() {
+            continue;
+        }
+        write(&path, &content)?;
+        created.push(path);
+    }
+    Ok(created)
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, content).with_context(|| format!("failed to write {}", path.display()))
+}
+
+fn update(path: &Path, f: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let updated = f(&content).with_context(|| format!("failed to update {}", path.display()))?;
+    write(path, &updated)
+}
+
+fn render(year: u16, day: u8, title: &str) -> String {
+    TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{NN}}", &format!("{day:02}"))
+        .replace("{{N}}", &day.to_string())
+        .replace("{{TITLE}}", &format!("{title:?}"))
+}
+
+/// `content` with `entry` added to the list of the `list` macro, keeping
+/// it sorted.
+fn register(content: &str, list: &str, entry: &str) -> Result<String> {
+    let open = format!("{list} {{\n");
+    let start = content
+        .find(&open)
+        .ok_or_else(|| anyhow!("no {list} list"))?
+        + open.len();
+    let end = start
+        + content[start..]
+            .find("}\n")
+            .ok_or_else(|| anyhow!("unterminated {list} list"))?;
+
+    let entry = format!("    {entry},");
+    let mut entries: Vec<&str> = content[start..end].lines().collect();
+    if entries.contains(&entry.as_str()) {
+        bail!("{} is already registered", entry.trim());
+    }
+    entries.push(&entry);
+    entries.sort();
+    Ok(format!(
+        "{}{}\n{}",
+        &content[..start],
+        entries.join("\n"),
+        &content[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    static LIB: &str = "mod x;\n\nyears! {\n    y2025 => Y2025 = 2025,\n}\n\nfn f() {}\n";
+
+    static YEAR: &str =
+        "//! Advent of Code 2025.\n\ndays! {\n    day01::Day01,\n    day03::Day03,\n}\n";
+
+    #[test_log::test]
+    fn test_register() {
+        let year = register(YEAR, "days!", "day02::Day02").unwrap();
+        assert!(year == YEAR.replace("Day01,\n", "Day01,\n    day02::Day02,\n"));
+        assert!(register(YEAR, "days!", "day03::Day03").is_err());
+        assert!(register("fn f() {}", "days!", "day02::Day02").is_err());
+    }
+
+    #[test_log::test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2025")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/y2025/mod.rs"), YEAR).unwrap();
+
+        let input = root.join("input");
+        let created = new_day(&root, &input, 2025, 13, "Some \"Title\"").unwrap();
+        assert!(created.len() == 4);
+        let module = fs::read_to_string(root.join("src/y2025/day13.rs")).unwrap();
+        assert!(module.contains("pub struct Day13;"));
+        assert!(module.contains(r#"const TITLE: &'static str = "Some \"Title\"";"#));
+        assert!(module.contains("    use crate::y2025::day13::*;\n"));
+        assert!(input.join("day13.txt").is_file());
+        assert!(root.join("examples/2025/day13/example.in").is_file());
+        assert!(root.join("examples/2025/day13/example.out").is_file());
+        let year = fs::read_to_string(root.join("src/y2025/mod.rs")).unwrap();
+        assert!(year.contains("    day03::Day03,\n    day13::Day13,\n}"));
+        assert!(new_day(&root, &input, 2025, 13, "x").is_err());
+
+        let created = new_day(&root, &input.join("2026"), 2026, 1, "x").unwrap();
+        assert!(created.len() == 5);
+        let year = fs::read_to_string(root.join("src/y2026/mod.rs")).unwrap();
+        assert!(year.contains("days! {\n    day01::Day01,\n}"));
+        let lib = fs::read_to_string(root.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("    y2025 => Y2025 = 2025,\n    y2026 => Y2026 = 2026,\n}"));
+        assert!(input.join("2026/day01.txt").is_file());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
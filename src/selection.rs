@@ -0,0 +1,142 @@
+use std::fmt;
+
+use anyhow::{Result, anyhow};
+use itertools::Itertools;
+
+use crate::{Day, Year, error::Failure};
+
+/// Days of a year picked on the command line.
+///
+/// A selection is a comma separated list of days and inclusive ranges:
+/// `8`, `1,4,9`, `3..7` or `3-7`, `5-` or `5..` (from day 5 on), `-3` or
+/// `..3` (up to day 3). `0` and `all` select every day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaySelection {
+    year: Year,
+    days: Vec<Day>,
+}
+
+impl DaySelection {
+    pub fn all(year: Year) -> Self {
+        DaySelection {
+            year,
+            days: year.days().collect(),
+        }
+    }
+
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    pub fn days(&self) -> &[Day] {
+        &self.days
+    }
+
+    pub fn is_all(&self) -> bool {
+        self.days.len() == self.year.days().count()
+    }
+
+    fn day(n: &str) -> Result<u8> {
+        n.trim()
+            .parse::<u8>()
+            .map_err(|_| anyhow!("invalid day '{}'", n.trim()))
+    }
+
+    /// Days of `year` selected by `s`.
+    pub fn parse(year: Year, s: &str) -> Result<Self> {
+        let last = year.days().last().map_or(0, |day| day.number());
+        if matches!(s.trim(), "0" | "all") {
+            return Ok(DaySelection::all(year));
+        }
+
+        let mut numbers = vec![];
+        for item in s.split(',') {
+            let range = item.split_once("..").or_else(|| item.split_once('-'));
+            match range {
+                Some((lower, upper)) => {
+                    let lower = match lower.trim() {
+                        "" => 1,
+                        n => Self::day(n)?,
+                    };
+                    let upper = match upper.trim().trim_start_matches('=') {
+                        "" => last,
+                        n => Self::day(n)?,
+                    };
+                    if lower > upper {
+                        return Err(anyhow!("empty day range '{}'", item.trim()));
+                    }
+                    numbers.extend(lower..=upper);
+                }
+                None => numbers.push(Self::day(item)?),
+            }
+        }
+
+        numbers
+            .into_iter()
+            .sorted()
+            .dedup()
+            .map(|n| {
+                year.day(n).ok_or_else(|| {
+                    Failure::UnknownDay.error(format!("{year} has no day {n}, days are 1-{last}"))
+                })
+            })
+            .collect::<Result<_>>()
+            .map(|days| DaySelection { year, days })
+    }
+}
+
+impl fmt::Display for DaySelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_all() {
+            return write!(f, "all days of {}", self.year);
+        }
+        write!(f, "{} of {}", self.days.iter().join(", "), self.year)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::selection::*;
+
+    fn days(s: &str) -> Vec<u8> {
+        DaySelection::parse(Year::Y2025, s)
+            .unwrap()
+            .days()
+            .iter()
+            .map(|d| d.number())
+            .collect()
+    }
+
+    #[test_log::test]
+    fn test_parse() {
+        assert!(days("8") == vec![8]);
+        assert!(days("1,4,9") == vec![1, 4, 9]);
+        assert!(days("9,4,4") == vec![4, 9]);
+        assert!(days("3..7") == vec![3, 4, 5, 6, 7]);
+        assert!(days("3..=5") == vec![3, 4, 5]);
+        assert!(days("3-5,10") == vec![3, 4, 5, 10]);
+        let last = Year::Y2025.days().count() as u8;
+        assert!(days("10-") == (10..=last).collect::<Vec<_>>());
+        assert!(days("..2") == vec![1, 2]);
+        assert!(days("0").len() == last as usize);
+        assert!(days("all").len() == last as usize);
+    }
+
+    #[test_log::test]
+    fn test_parse_errors() {
+        let parse = |s| DaySelection::parse(Year::Y2025, s);
+        let e = parse("42").unwrap_err();
+        assert!(Failure::find(&e) == Some(Failure::UnknownDay));
+        let e = parse("0-3").unwrap_err();
+        assert!(Failure::find(&e) == Some(Failure::UnknownDay));
+        assert!(parse("7-3").is_err());
+        assert!(parse("x").is_err());
+    }
+
+    #[test_log::test]
+    fn test_display() {
+        let days = DaySelection::parse(Year::Y2025, "3,1").unwrap();
+        assert!(days.to_string() == "Day01, Day03 of 2025");
+        assert!(DaySelection::all(Year::Y2025).to_string() == "all days of 2025");
+    }
+}
@@ -0,0 +1,109 @@
+use std::{cell::Cell, time::Duration, time::Instant};
+
+/// Time spent in each phase of a part.
+///
+/// Reading and parsing are measured when loading the input, once for all
+/// the parts of a run; solving is measured for each part.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Timings {
+    pub read: Duration,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.read + self.parse + self.solve
+    }
+}
+
+pub(crate) enum Phase {
+    Read,
+    Parse,
+}
+
+thread_local! {
+    static PHASES: Cell<Timings> = Cell::new(Timings::default());
+}
+
+/// Run `f`, accounting its duration to `phase` of the part being timed.
+pub(crate) fn measure<T>(phase: Phase, f: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let res = f();
+    let elapsed = start.elapsed();
+    PHASES.with(|phases| {
+        let mut timings = phases.get();
+        match phase {
+            Phase::Read => timings.read += elapsed,
+            Phase::Parse => timings.parse += elapsed,
+        }
+        phases.set(timings);
+    });
+    res
+}
+
+/// Run a whole part and return its result along with its timings.
+pub(crate) fn timed<T>(f: impl FnOnce() -> T) -> (T, Timings) {
+    PHASES.with(|phases| phases.set(Timings::default()));
+    let start = Instant::now();
+    let res = f();
+    let elapsed = start.elapsed();
+    let mut timings = PHASES.with(|phases| phases.take());
+    timings.solve = elapsed.saturating_sub(timings.read + timings.parse);
+    (res, timings)
+}
+
+/// CPU time consumed so far by the whole process, all threads included.
+#[cfg(unix)]
+pub fn cpu_time() -> Duration {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `ts` is a valid timespec for the duration of the call
+    let ret = unsafe { libc::clock_gettime(libc::CLOCK_PROCESS_CPUTIME_ID, &mut ts) };
+    match ret {
+        0 => Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32),
+        _ => Duration::ZERO,
+    }
+}
+
+#[cfg(not(unix))]
+pub fn cpu_time() -> Duration {
+    Duration::ZERO
+}
+
+/// Human readable duration with a unit suited to its magnitude.
+pub fn format_duration(duration: Duration) -> String {
+    let ns = duration.as_nanos();
+    match ns {
+        0..1_000 => format!("{} ns", ns),
+        1_000..1_000_000 => format!("{:.2} us", ns as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.2} ms", ns as f64 / 1e6),
+        _ => format!("{:.2} s", ns as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::timing::*;
+
+    #[test_log::test]
+    fn test_timed() {
+        let (res, timings) = timed(|| {
+            measure(Phase::Read, || std::thread::sleep(Duration::from_millis(2)));
+            measure(Phase::Parse, || 42)
+        });
+        assert!(res == 42);
+        assert!(timings.read >= Duration::from_millis(2));
+        assert!(timings.total() >= timings.read + timings.parse);
+    }
+
+    #[test_log::test]
+    fn test_format_duration() {
+        assert!(format_duration(Duration::from_nanos(999)) == "999 ns");
+        assert!(format_duration(Duration::from_nanos(1_500)) == "1.50 us");
+        assert!(format_duration(Duration::from_millis(12)) == "12.00 ms");
+        assert!(format_duration(Duration::from_secs(2)) == "2.00 s");
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    fs,
+    io::{Write, stdout},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Result;
+
+use crate::{InputSource, RunOptions, examples::Fixture, report::Format, selection::DaySelection};
+
+/// Modification time and size of a watched file, `None` if it is missing.
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Files the run of the selected `days` depends on: their inputs and the
+/// fixtures under `examples`.
+fn watched(days: &DaySelection, options: &RunOptions, examples: Option<&Path>) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = days
+        .days()
+        .iter()
+        .filter_map(|day| match options.input_for(day) {
+            InputSource::File(path) => Some(path),
+            _ => None,
+        })
+        .collect();
+    for fixture in fixtures(days, examples) {
+        paths.push(fixture.expected());
+        paths.push(fixture.input);
+    }
+    paths
+}
+
+fn fixtures(days: &DaySelection, examples: Option<&Path>) -> Vec<Fixture> {
+    examples
+        .and_then(|dir| Fixture::discover(dir).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|fixture| days.days().contains(&fixture.day))
+        .collect()
+}
+
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, Stamp)> {
+    paths
+        .iter()
+        .map(|path| {
+            let stamp = fs::metadata(path)
+                .ok()
+                .and_then(|m| Some((m.modified().ok()?, m.len())));
+            (path.clone(), stamp)
+        })
+        .collect()
+}
+
+/// Run the selected `days` on their inputs, then on the fixtures under
+/// `examples`. Errors are shown rather than returned, to keep watching.
+fn run_once(days: &DaySelection, options: &RunOptions, examples: Option<&Path>, format: Format) {
+    let mut reporter = format.reporter();
+    if let Err(e) = crate::run(days, options, reporter.as_mut()) {
+        println!("Error: {e:#}");
+    }
+    if let Some(Err(e)) = examples.filter(|dir| dir.exists()).map(Fixture::discover) {
+        println!("Error: {e:#}");
+    }
+    let options = RunOptions {
+        cache: None,
+        history: None,
+        ..options.clone()
+    };
+    for fixture in fixtures(days, examples) {
+        let day = fixture.day;
+        let result = fixture.options(&options).and_then(|fixture_options| {
+            let Some(fixture_options) = fixture_options else {
+                return Ok(());
+            };
+            let module = fixture_options.module(&day)?;
+            let mut reporter = format.reporter();
+            reporter.begin(&format!("{day} example {}", fixture.name()));
+            module.run(
+                &day,
+                &fixture_options.input_for(&day),
+                &fixture_options,
+                reporter.as_mut(),
+            )?;
+            reporter.end();
+            Ok(())
+        });
+        if let Err(e) = result {
+            println!("Error: {day} example {}: {e:#}", fixture.name());
+        }
+    }
+}
+
+/// Run the selected `days` on their inputs and the fixtures under
+/// `examples`, then again whenever one of these files changes, polling them
+/// every `interval`. Runs until interrupted.
+pub fn watch(
+    days: &DaySelection,
+    options: &RunOptions,
+    examples: Option<&Path>,
+    format: Format,
+    interval: Duration,
+) -> Result<()> {
+    let mut last = None;
+    loop {
+        let current = snapshot(&watched(days, options, examples));
+        if last.as_ref() != Some(&current) {
+            // clear the screen and the scrollback
+            print!("\x1b[2J\x1b[3J\x1b[H");
+            run_once(days, options, examples, format);
+            println!("\nWatching {} files, Ctrl-C to stop", current.len());
+            stdout().flush()?;
+            // the files may have changed during the run
+            last = Some(snapshot(&watched(days, options, examples)));
+        }
+        thread::sleep(interval);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Year, watch::*};
+
+    #[test_log::test]
+    fn test_watched() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let examples = dir.join("examples");
+        fs::create_dir_all(examples.join("2025/day01")).unwrap();
+        fs::create_dir_all(examples.join("2025/day02")).unwrap();
+        fs::write(examples.join("2025/day01/small.in"), "L68\n").unwrap();
+        fs::write(examples.join("2025/day02/small.in"), "11-22\n").unwrap();
+        let days = DaySelection::parse(Year::Y2025, "1").unwrap();
+        let options = RunOptions {
+            input_dir: dir.join("input"),
+            ..RunOptions::default()
+        };
+
+        let paths = watched(&days, &options, Some(&examples));
+        assert!(
+            paths
+                == vec![
+                    dir.join("input/day01.txt"),
+                    examples.join("2025/day01/small.out"),
+                    examples.join("2025/day01/small.in"),
+                ]
+        );
+        let before = snapshot(&paths);
+        assert!(before[0].1.is_none() && before[2].1.is_some());
+        fs::write(examples.join("2025/day01/small.out"), "part1 = 3\n").unwrap();
+        assert!(snapshot(&paths) != before);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::{Context, Result, bail};
+use tracing::debug;
+
+use crate::{Answer, Solution};
+
+/// Rotations of the dial, negative to the left.
+fn parse(input: &str) -> Result<Vec<i32>> {
+    input
+        .lines()
+        .map(|line| {
+            let distance = || {
+                line[1..]
+                    .parse::<i32>()
+                    .with_context(|| format!("invalid distance in '{line}'"))
+            };
+            match line.chars().next() {
+                Some('L') => Ok(-distance()?),
+                Some('R') => distance(),
+                _ => bail!("invalid rotation '{line}'"),
+            }
+        })
+        .collect()
+}
+
+fn process(input: &[i32]) -> i32 {
+    let mut pos = 50;
+
+    input.iter().fold(0, |acc, rotation| {
+        let mut n = 0;
+        pos += rotation;
+        pos %= 100;
+        if pos < 0 {
+            pos += 100;
+        }
+        if pos == 0 {
+            n += 1;
+        }
+        acc + n
+    })
+}
+
+fn process2(input: &[i32]) -> i32 {
+    let mut pos = 50;
+    input.iter().fold(0, |acc, rotation| {
+        debug!(?rotation, ?acc, ">>> ");
+        let mut n = 0;
+        let prev = pos;
+        // full turns, then what is left of the rotation
+        n += (rotation / 100).abs();
+        pos += rotation % 100;
+        if pos < 0 {
+            if prev != 0 {
+                n += 1;
+            }
+            pos += 100;
+        }
+        if pos == 0 {
+            n += 1;
+        }
+        if pos >= 100 {
+            n += 1;
+            pos -= 100;
+        }
+        debug!(?prev, ?pos, ?n, new_acc = ?(acc + n), " ");
+        acc + n
+    })
+}
+
+#[derive(Debug, Default)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Secret Entrance";
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>> {
+        parse(input)
+    }
+    fn part1(&self, input: &Vec<i32>) -> Result<Answer> {
+        let res = process(input);
+        Ok(res.into())
+    }
+
+    fn part2(&self, input: &Vec<i32>) -> Result<Answer> {
+        let res = process2(input);
+        Ok(res.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2025::day01::*;
+
+    #[test_log::test]
+    fn test_parse() {
+        assert!(parse("L68\nR30\n").unwrap() == vec![-68, 30]);
+        assert!(parse("L68\nX30\n").is_err());
+        assert!(parse("R\n").is_err());
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{Context, Result};
+use tracing::debug;
+
+use crate::{Answer, Solution, single_line};
+
+/// Ranges of product IDs, as their first and last ID.
+fn parse(input: &str) -> Result<Vec<(u64, u64)>> {
+    single_line(input)?
+        .split(',')
+        .map(|range| {
+            let (min, max) = range
+                .split_once('-')
+                .with_context(|| format!("invalid range '{range}'"))?;
+            let id = |id: &str| {
+                id.parse::<u64>()
+                    .with_context(|| format!("invalid ID in range '{range}'"))
+            };
+            Ok((id(min)?, id(max)?))
+        })
+        .collect()
+}
+
+fn is_invalid_part1(number: u64) -> bool {
+    let len: u32 = ((number as f32).log10() + 1.0).floor() as u32 / 2;
+    let a = number / (10_u64.pow(len));
+    let b = number % (10_u64.pow(len));
+    a == b
+}
+
+fn process_part1(input: &[(u64, u64)]) -> u128 {
+    // a sum of u64 values, which doesn't fit in a u64 for wide ranges
+    let mut invalid = 0;
+
+    for &(min, max) in input {
+        debug!(?min, ?max);
+        for i in min..=max {
+            if is_invalid_part1(i) {
+                invalid += u128::from(i);
+            }
+        }
+    }
+    invalid
+}
+
+fn is_invalid_part2(number: u64) -> bool {
+    let len: u32 = ((number as f32).log10() + 1.0).floor() as u32;
+    for i in 1..((len / 2) + 1) {
+        let mut failed = false;
+        if !len.is_multiple_of(i) {
+            continue;
+        }
+
+        let a = number % (10_u64.pow(i));
+        let mut tmp = number / (10_u64.pow(i));
+        while tmp > 0 {
+            let b = tmp % (10_u64.pow(i));
+            if a != b {
+                failed = true;
+                break;
+            }
+            tmp /= 10_u64.pow(i);
+        }
+
+        if !failed {
+            return true;
+        }
+    }
+    false
+}
+
+fn process_part2(input: &[(u64, u64)]) -> u128 {
+    let mut invalid = 0;
+
+    for &(min, max) in input {
+        debug!(?min, ?max);
+        for i in min..=max {
+            if is_invalid_part2(i) {
+                invalid += u128::from(i);
+            }
+        }
+    }
+    invalid
+}
+
+#[derive(Debug, Default)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(u64, u64)>;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Gift Shop";
+
+    fn parse(&self, input: &str) -> Result<Vec<(u64, u64)>> {
+        parse(input)
+    }
+    fn part1(&self, input: &Vec<(u64, u64)>) -> Result<Answer> {
+        let res = process_part1(input);
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Vec<(u64, u64)>) -> Result<Answer> {
+        let res = process_part2(input);
+        Ok(res.into())
+    }
+}
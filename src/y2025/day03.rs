@@ -0,0 +1,77 @@
+use anyhow::{Context, Result, bail};
+use tracing::debug;
+
+use crate::{Answer, Solution};
+
+#[derive(Debug, Default)]
+pub struct Day03;
+
+/// Joltage ratings of the batteries of each bank.
+fn parse(input: &str) -> Result<Vec<Vec<u64>>> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(u64::from)
+                        .with_context(|| format!("invalid joltage '{c}' in '{line}'"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn process(input: &[Vec<u64>], size: usize) -> Result<u64> {
+    let mut total: u64 = 0;
+
+    for line in input {
+        if line.len() < size {
+            bail!("a bank has {} batteries, {size} needed", line.len());
+        }
+        let mut pos = 0;
+        let mut size = size;
+        debug!(?line);
+
+        while size > 0 {
+            let mut i = 0;
+            let mut max_pos = 0;
+            let scan = &line[pos..(line.len() - size + 1)];
+            let max: u64 = scan.iter().fold(0, |max, &c| {
+                i += 1;
+                if c > max {
+                    max_pos = i;
+                    c
+                } else {
+                    max
+                }
+            });
+            debug!(?max, ?pos);
+
+            pos += max_pos;
+            size -= 1;
+            total = total
+                .checked_add(10_u64.pow(size as u32) * max)
+                .context("the total output joltage overflows")?;
+        }
+    }
+    Ok(total)
+}
+
+impl Solution for Day03 {
+    type Input = Vec<Vec<u64>>;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Lobby";
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<u64>>> {
+        parse(input)
+    }
+    fn part1(&self, input: &Vec<Vec<u64>>) -> Result<Answer> {
+        let res = process(input, 2)?;
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Vec<Vec<u64>>) -> Result<Answer> {
+        let res = process(input, 12)?;
+        Ok(res.into())
+    }
+}
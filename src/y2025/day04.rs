@@ -1,8 +1,8 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use itertools::Itertools;
 use tracing::debug;
 
-use crate::{AocRun, load_input_file};
+use crate::{Answer, Solution, lines};
 
 #[derive(Debug, Default)]
 pub struct Day04;
@@ -72,6 +72,23 @@ fn find_number_of_rolls(
     valid_positions
 }
 
+/// Rows of the grid of rolls `@` and empty spaces `.`, all as wide.
+fn parse(input: &str) -> Result<Vec<String>> {
+    let grid = lines(input);
+    if grid.len() < 2 {
+        bail!("the grid has {} rows, at least 2 needed", grid.len());
+    }
+    for row in &grid {
+        if row.len() != grid[0].len() {
+            bail!("row '{row}' is not as wide as the first one");
+        }
+        if let Some(c) = row.chars().find(|c| !matches!(c, '@' | '.')) {
+            bail!("invalid position '{c}' in '{row}'");
+        }
+    }
+    Ok(grid)
+}
+
 fn iteration(input: &[String]) -> Vec<Position> {
     let mut valid_positions: Vec<Position> = vec![];
 
@@ -87,23 +104,28 @@ fn iteration(input: &[String]) -> Vec<Position> {
     ));
 
     // iterate
-    let mut i = 1;
-    for (prev, cur, next) in input.iter().tuple_windows() {
+    for (i, (prev, cur, next)) in (1..).zip(input.iter().tuple_windows()) {
         valid_positions.extend(find_number_of_rolls(cur, Some(prev), Some(next), i));
-        i += 1;
     }
 
     valid_positions
 }
 
-impl AocRun for Day04 {
-    fn run1(&self) -> Result<i64> {
-        let res = iteration(&load_input_file("./input/day04.txt")?).len();
-        Ok(res as i64)
+impl Solution for Day04 {
+    type Input = Vec<String>;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Printing Department";
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        parse(input)
     }
-    fn run2(&self) -> Result<i64> {
-        let res = process(load_input_file("./input/day04.txt")?);
-        Ok(res as i64)
+    fn part1(&self, input: &Vec<String>) -> Result<Answer> {
+        let res = iteration(input).len();
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Vec<String>) -> Result<Answer> {
+        let res = process(input.clone());
+        Ok(res.into())
     }
 }
 
@@ -134,31 +156,3 @@ fn process(input: Vec<String>) -> usize {
 
     total
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::{day04::*, tests::load_test_input};
-
-    static TEST_INPUT: &str = r#"
-..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.
-"#;
-
-    #[test_log::test]
-    fn test1() {
-        assert!(iteration(&load_test_input(TEST_INPUT)).len() == 13);
-    }
-
-    #[test_log::test]
-    fn test2() {
-        assert!(process(load_test_input(TEST_INPUT)) == 43);
-    }
-}
@@ -0,0 +1,171 @@
+use std::collections::BTreeSet;
+
+use anyhow::{Context, Result, bail};
+use tracing::debug;
+
+use crate::{Answer, Solution};
+
+#[derive(Debug, Default)]
+pub struct Day05;
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct Interval {
+    lower: u64,
+    upper: u64,
+}
+
+fn fill_fresh_intervals(set: &mut BTreeSet<Interval>, lower: u64, upper: u64) {
+    let interval = Interval { lower, upper };
+    let mut entry_containg_lower = None;
+    let mut entry_containg_upper = None;
+
+    for entry in set.iter() {
+        // check if lower value is in set
+        if lower > entry.lower && lower < entry.upper {
+            entry_containg_lower = Some(entry);
+        }
+        // check if upper value is in set
+        if upper > entry.lower && upper < entry.upper {
+            entry_containg_upper = Some(entry);
+        }
+    }
+
+    if let Some(entry) = entry_containg_lower {
+        set.insert(Interval {
+            lower: entry.lower,
+            upper,
+        });
+    } else if let Some(entry) = entry_containg_upper {
+        set.insert(Interval {
+            lower,
+            upper: entry.upper,
+        });
+    } else {
+        set.insert(interval);
+    }
+
+    // TODO intervals are not merged!!
+}
+
+fn check_fresh(set: &BTreeSet<Interval>, n: u64) -> i32 {
+    let mut total = 0;
+    for entry in set.iter() {
+        if n >= entry.lower && n <= entry.upper {
+            total += 1;
+            // break immediately because we didn't merge intervals
+            break;
+        }
+    }
+    total
+}
+
+/// Number of fresh IDs, which doesn't fit in a u64 when the intervals
+/// cover all of them.
+fn check_fresh2(set: &BTreeSet<Interval>) -> u128 {
+    let mut prev_upper = 0;
+    set.iter().fold(0, |total, entry| {
+        let (lower, upper) = (u128::from(entry.lower), u128::from(entry.upper));
+        // intervals are not merged, skip if current entry was contained in
+        // previous one
+        if prev_upper > upper {
+            return total;
+        }
+        let add = upper - lower.max(prev_upper) + 1;
+        // store upper bound of current interval
+        prev_upper = upper + 1;
+        total + add
+    })
+}
+
+/// Ranges of fresh ingredient IDs, followed by the available ingredients.
+#[derive(Debug)]
+pub struct Inventory {
+    fresh: BTreeSet<Interval>,
+    available: Vec<u64>,
+}
+
+fn parse(input: &str) -> Result<Inventory> {
+    let mut lines = input.lines();
+    let mut fresh = BTreeSet::new();
+    for line in lines.by_ref().take_while(|l| !l.is_empty()) {
+        let (lower, upper) = line
+            .split_once('-')
+            .with_context(|| format!("invalid range '{line}'"))?;
+        let id = |id: &str| {
+            id.parse()
+                .with_context(|| format!("invalid ID in range '{line}'"))
+        };
+        let (lower, upper) = (id(lower)?, id(upper)?);
+        if lower > upper {
+            bail!("empty range '{line}'");
+        }
+        fill_fresh_intervals(&mut fresh, lower, upper);
+    }
+    let available = lines
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.parse()
+                .with_context(|| format!("invalid ingredient ID '{line}'"))
+        })
+        .collect::<Result<_>>()?;
+    Ok(Inventory { fresh, available })
+}
+
+fn process(input: &Inventory) -> i32 {
+    let total = input
+        .available
+        .iter()
+        .map(|&id| check_fresh(&input.fresh, id))
+        .sum();
+    debug!("total={}", total);
+    total
+}
+
+fn process2(input: &Inventory) -> u128 {
+    let total = check_fresh2(&input.fresh);
+    debug!("process2 total={}", total);
+    total
+}
+
+impl Solution for Day05 {
+    type Input = Inventory;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Cafeteria";
+    const COMMANDS: &'static [(&'static str, &'static str)] =
+        &[("query ID", "whether the ingredient ID is fresh")];
+
+    fn parse(&self, input: &str) -> Result<Inventory> {
+        parse(input)
+    }
+    fn part1(&self, input: &Inventory) -> Result<Answer> {
+        let res = process(input);
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Inventory) -> Result<Answer> {
+        let res = process2(input);
+        Ok(res.into())
+    }
+    fn command(&self, input: &Inventory, name: &str, args: &[&str]) -> Result<Answer> {
+        match (name, args) {
+            ("query", [id]) => match check_fresh(&input.fresh, id.parse()?) {
+                0 => Ok("spoiled".into()),
+                _ => Ok("fresh".into()),
+            },
+            _ => bail!("usage: query ID"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2025::day05::*;
+
+    #[test_log::test]
+    fn test_parse() {
+        let inventory = parse("3-5\n10-14\n\n1\n5\n").unwrap();
+        assert!(inventory.fresh.len() == 2);
+        assert!(inventory.available == vec![1, 5]);
+        assert!(parse("1-3\n\nx\n").is_err());
+        assert!(parse("1+3\n").is_err());
+    }
+}
@@ -0,0 +1,196 @@
+use anyhow::{Context, Result, bail};
+use tracing::debug;
+
+use crate::{Answer, Solution, lines};
+
+#[derive(Debug, Default)]
+pub struct Day06;
+
+/// Worksheet of problems: rows of numbers above a line of operators. Part 2
+/// reads the digits of the rows in columns, so they are kept as written,
+/// padded with spaces to the same width.
+#[derive(Debug)]
+pub struct Worksheet {
+    rows: Vec<String>,
+    numbers: Vec<Vec<u64>>,
+    operators: String,
+}
+
+fn parse(input: &str) -> Result<Worksheet> {
+    let mut rows = lines(input);
+    // editors may trim the trailing spaces of the last column
+    let width = rows.iter().map(String::len).max().unwrap_or_default();
+    for row in rows.iter_mut() {
+        *row = format!("{row:<width$}");
+    }
+    let operators = rows.pop().context("empty worksheet")?;
+    if let Some(c) = operators.chars().find(|c| !matches!(c, '+' | '*' | ' ')) {
+        bail!("invalid operator '{c}'");
+    }
+    let count = operators.split_whitespace().count();
+    let numbers = rows
+        .iter()
+        .map(|row| {
+            let numbers = row
+                .split_whitespace()
+                .map(|n| n.parse().with_context(|| format!("invalid number '{n}'")))
+                .collect::<Result<Vec<u64>>>()?;
+            if numbers.len() != count {
+                bail!("{} numbers in '{row}' for {count} operators", numbers.len());
+            }
+            Ok(numbers)
+        })
+        .collect::<Result<_>>()?;
+    Ok(Worksheet {
+        rows,
+        numbers,
+        operators,
+    })
+}
+
+/// `total` combined with `operand` by `operator`, an error if it overflows.
+fn apply(operator: char, total: u64, operand: u64) -> Result<u64> {
+    match operator {
+        '+' => total.checked_add(operand),
+        '*' => total.checked_mul(operand),
+        _ => unreachable!(),
+    }
+    .with_context(|| format!("{total} {operator} {operand} overflows"))
+}
+
+fn process(input: &Worksheet) -> Result<u64> {
+    let mut totals: Vec<u64> = vec![];
+    let operations: Vec<char> = input.operators.chars().filter(|c| *c != ' ').collect();
+
+    // init totals vector with neutral element
+    for operator in operations.iter() {
+        match *operator {
+            '+' => totals.push(0),
+            '*' => totals.push(1),
+            _ => unreachable!(),
+        }
+    }
+
+    for numbers in &input.numbers {
+        for (pos, (operand, operator)) in numbers.iter().zip(operations.iter()).enumerate() {
+            totals[pos] = apply(*operator, totals[pos], *operand)?;
+        }
+    }
+
+    totals
+        .iter()
+        .try_fold(0, |acc, total| apply('+', acc, *total))
+}
+
+#[derive(Debug)]
+struct Op<'a> {
+    operation: char,
+    size: usize,
+    total: u64,
+    column: Vec<&'a str>,
+}
+
+fn process2(input: &Worksheet) -> Result<u64> {
+    let mut operations: Vec<Op> = vec![];
+    let mut size = 0;
+    let mut total = 0;
+    for c in input.operators.chars() {
+        let len = operations.len();
+        match c {
+            '+' => {
+                if len > 0 {
+                    operations[len - 1].size = size;
+                }
+                operations.push(Op {
+                    operation: c,
+                    size,
+                    total: 0,
+                    column: vec![],
+                });
+                size = 0;
+            }
+            '*' => {
+                if len > 0 {
+                    operations[len - 1].size = size;
+                }
+                operations.push(Op {
+                    operation: c,
+                    size,
+                    total: 1,
+                    column: vec![],
+                });
+                size = 0;
+            }
+            ' ' => {
+                size += 1;
+            }
+            _ => unreachable!(),
+        }
+    }
+    // the last column spans up to the end of the rows
+    if let Some(last) = operations.last_mut() {
+        last.size = size + 1;
+    }
+
+    for line in &input.rows {
+        let mut prev = 0;
+        for op in operations.iter_mut() {
+            op.column.push(&line[prev..(prev + op.size)]);
+            debug!(?prev, ?op.size, ?op.column);
+            prev += op.size + 1;
+        }
+    }
+
+    for op in operations.iter_mut() {
+        debug!("{:#?}", op);
+        for i in 0..op.size {
+            let n = op.column.iter().try_fold(0, |acc, x| {
+                match x.chars().nth(i).unwrap().to_digit(10) {
+                    Some(x) => apply('+', apply('*', acc, 10)?, x.into()),
+                    None => Ok(acc),
+                }
+            })?;
+            op.total = apply(op.operation, op.total, n)?;
+            debug!(?n);
+        }
+        debug!(?op.total);
+        total = apply('+', total, op.total)?;
+    }
+    debug!(total);
+    Ok(total)
+}
+
+impl Solution for Day06 {
+    type Input = Worksheet;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Trash Compactor";
+
+    fn parse(&self, input: &str) -> Result<Worksheet> {
+        parse(input)
+    }
+    fn part1(&self, input: &Worksheet) -> Result<Answer> {
+        let res = process(input)?;
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Worksheet) -> Result<Answer> {
+        let res = process2(input)?;
+        Ok(res.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2025::day06::*;
+
+    #[test_log::test]
+    fn test_trimmed() {
+        let input = "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n*   +   *   +";
+        assert!(process2(&parse(input).unwrap()).unwrap() == 3263827);
+    }
+
+    #[test_log::test]
+    fn test_overflow() {
+        assert!(process(&parse("4294967296\n4294967296\n*").unwrap()).is_err());
+        assert!(apply('+', u64::MAX, 1).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::{BTreeSet, HashMap};
+
+use anyhow::{Context, Result, bail};
+use tracing::debug;
+
+use crate::{Answer, Solution, lines};
+
+#[derive(Debug, Default)]
+pub struct Day07;
+
+/// Rows of the manifold, along with the column the beam starts from in the
+/// first one.
+#[derive(Debug)]
+pub struct Manifold {
+    start: usize,
+    rows: Vec<String>,
+}
+
+fn parse(input: &str) -> Result<Manifold> {
+    let rows = lines(input);
+    let start = rows
+        .first()
+        .and_then(|row| row.chars().position(|c| c == 'S'))
+        .context("no start S in the first row")?;
+    for row in &rows {
+        if row.len() != rows[0].len() {
+            bail!("row '{row}' is not as wide as the first one");
+        }
+        if let Some(c) = row.chars().find(|c| !matches!(c, '.' | '^' | 'S')) {
+            bail!("invalid position '{c}' in '{row}'");
+        }
+        if row.starts_with('^') || row.ends_with('^') {
+            bail!("splitter on the edge in '{row}'");
+        }
+    }
+    Ok(Manifold { start, rows })
+}
+
+fn process(input: &Manifold) -> i32 {
+    let mut positions: BTreeSet<usize> = BTreeSet::new();
+    let it = input.rows.iter().skip(1);
+    let mut total = 0;
+
+    positions.insert(input.start);
+
+    debug!(?positions);
+
+    it.for_each(|line| {
+        let mut splitters: Vec<usize> = vec![];
+        for elem in positions.iter() {
+            if line.chars().nth(*elem).unwrap() == '^' {
+                total += 1;
+                splitters.push(*elem);
+            }
+        }
+        for s in splitters {
+            positions.insert(s - 1);
+            positions.insert(s + 1);
+            positions.remove(&s);
+        }
+        debug!(?positions);
+    });
+
+    total
+}
+
+fn recurse(
+    input: &[String],
+    pos: usize,
+    depth: usize,
+    hash: &mut HashMap<(usize, usize), u64>,
+) -> Result<u64> {
+    debug!(?pos, ?depth);
+
+    let line = match input.get(depth) {
+        Some(l) => l,
+        None => {
+            debug!(?depth, ?pos, "END OF TIMELINE");
+            return Ok(1);
+        }
+    };
+
+    if hash.contains_key(&(pos, depth)) {
+        let n = hash[&(pos, depth)];
+        debug!(?depth, ?pos, ?n, "using hash");
+        return Ok(n);
+    }
+
+    if line.chars().nth(pos).unwrap() == '^' {
+        let n = recurse(input, pos - 1, depth + 2, hash)?
+            .checked_add(recurse(input, pos + 1, depth + 2, hash)?)
+            .context("the number of timelines overflows")?;
+
+        debug!(?depth, ?pos, ?n, "node complete, insert hash");
+        hash.insert((pos, depth), n);
+        Ok(n)
+    } else {
+        recurse(input, pos, depth + 2, hash)
+    }
+}
+
+fn process2(input: &Manifold) -> Result<u64> {
+    let mut hash: HashMap<(usize, usize), u64> = HashMap::new();
+
+    recurse(&input.rows, input.start, 2, &mut hash)
+}
+
+impl Solution for Day07 {
+    type Input = Manifold;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Laboratories";
+
+    fn parse(&self, input: &str) -> Result<Manifold> {
+        parse(input)
+    }
+    fn part1(&self, input: &Manifold) -> Result<Answer> {
+        let res = process(input);
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Manifold) -> Result<Answer> {
+        let res = process2(input)?;
+        Ok(res.into())
+    }
+}
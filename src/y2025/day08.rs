@@ -0,0 +1,143 @@
+use anyhow::{Context, Result, bail};
+use tracing::debug;
+
+use crate::params::Params;
+use crate::utils::dsu::Dsu;
+use crate::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Day08 {
+    connections: i32,
+}
+
+impl Default for Day08 {
+    fn default() -> Self {
+        Day08 { connections: 1000 }
+    }
+}
+
+#[derive(Debug)]
+struct JunctionBox {
+    x: i64,
+    y: i64,
+    z: i64,
+    circuit: i32,
+}
+
+impl JunctionBox {
+    fn dist_sq(&self, other: &Self) -> i64 {
+        (self.x - other.x) * (self.x - other.x)
+            + (self.y - other.y) * (self.y - other.y)
+            + (self.z - other.z) * (self.z - other.z)
+    }
+}
+
+/// Junction boxes, along with every pair of boxes sorted by distance.
+#[derive(Debug)]
+pub struct Boxes {
+    list: Vec<JunctionBox>,
+    sorted_dist: Vec<(i64, i32, i32)>,
+}
+
+fn parse(input: &str) -> Result<Boxes> {
+    let mut list = vec![];
+    for (circuit, line) in (0..).zip(input.lines()) {
+        let mut it = line.split(',').map(|n| {
+            n.parse::<i64>()
+                .with_context(|| format!("invalid coordinate in '{line}'"))
+        });
+        let mut next = || {
+            it.next()
+                .unwrap_or_else(|| bail!("missing coordinate in '{line}'"))
+        };
+        list.push(JunctionBox {
+            x: next()?,
+            y: next()?,
+            z: next()?,
+            circuit,
+        });
+    }
+
+    let mut sorted_dist: Vec<(i64, i32, i32)> = vec![];
+    for i in 0..list.len().saturating_sub(1) {
+        for j in (i + 1)..list.len() {
+            let a = &list[i];
+            let b = &list[j];
+            let dist = a.dist_sq(b);
+            sorted_dist.push((dist, i as i32, j as i32));
+        }
+    }
+    sorted_dist.sort_by_key(|(d, _, _)| *d);
+
+    Ok(Boxes { list, sorted_dist })
+}
+
+fn process(boxes: &Boxes, connections: i32) -> Result<u64> {
+    let list = &boxes.list;
+    let mut dsu = Dsu::new(list.len());
+    let mut it = boxes.sorted_dist.iter();
+    for _ in 0..connections {
+        let Some((_, jb1, jb2)) = it.next() else {
+            bail!(
+                "{connections} connections but only {} pairs of junction boxes",
+                boxes.sorted_dist.len()
+            );
+        };
+        debug!("Connecting a={}<->b={}", jb1, jb2);
+        let c1 = list[*jb1 as usize].circuit;
+        let c2 = list[*jb2 as usize].circuit;
+        dsu.union(c1, c2);
+    }
+
+    let mut sizes = dsu.get_sizes().clone();
+    sizes.sort();
+    sizes.reverse();
+    sizes
+        .iter()
+        .take(3)
+        .try_fold(1_u64, |acc, x| acc.checked_mul(*x as u64))
+        .context("the product of the circuit sizes overflows")
+}
+
+fn process2(boxes: &Boxes) -> Result<i64> {
+    let list = &boxes.list;
+    let mut dsu = Dsu::new(list.len());
+    let mut it = boxes.sorted_dist.iter();
+    let mut res = 0;
+    while dsu.component_sizes().len() > 1 {
+        let (_, jb1, jb2) = it.next().unwrap();
+        let c1 = list[*jb1 as usize].circuit;
+        let c2 = list[*jb2 as usize].circuit;
+        dsu.union(c1, c2);
+        res = list[*jb1 as usize]
+            .x
+            .checked_mul(list[*jb2 as usize].x)
+            .context("the product of the X coordinates overflows")?;
+    }
+
+    Ok(res)
+}
+
+impl Solution for Day08 {
+    type Input = Boxes;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Playground";
+
+    fn configure(&mut self, params: &Params) -> Result<()> {
+        if let Some(connections) = params.get("connections")? {
+            self.connections = connections;
+        }
+        Ok(())
+    }
+    fn parse(&self, input: &str) -> Result<Boxes> {
+        parse(input)
+    }
+    fn part1(&self, input: &Boxes) -> Result<Answer> {
+        let res = process(input, self.connections)?;
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Boxes) -> Result<Answer> {
+        let res = process2(input)?;
+        Ok(res.into())
+    }
+}
@@ -0,0 +1,151 @@
+use std::cmp::{max, min};
+
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::{Answer, Solution};
+
+#[derive(Debug, Default)]
+pub struct Day09;
+
+/// Red tile, the tiles of the input being the corners of a loop.
+#[derive(Clone, Debug)]
+pub struct Point {
+    x: i64,
+    y: i64,
+}
+
+fn parse(input: &str) -> Result<Vec<Point>> {
+    let points: Vec<Point> = input
+        .lines()
+        .map(|line| {
+            let Some((x, y)) = line.split_once(',') else {
+                bail!("invalid tile '{line}'");
+            };
+            let coordinate = |n: &str| {
+                n.parse::<i64>()
+                    .with_context(|| format!("invalid coordinate in '{line}'"))
+            };
+            Ok(Point {
+                x: coordinate(x)?,
+                y: coordinate(y)?,
+            })
+        })
+        .collect::<Result<_>>()?;
+    if points.len() < 2 {
+        bail!("{} red tiles, at least 2 needed", points.len());
+    }
+    Ok(points)
+}
+
+fn area(a: &Point, b: &Point) -> Result<i64> {
+    let side = |a: i64, b: i64| max(a, b).checked_sub(min(a, b))?.checked_add(1);
+    side(a.x, b.x)
+        .zip(side(a.y, b.y))
+        .and_then(|(length, width)| length.checked_mul(width))
+        .with_context(|| format!("the area between {a:?} and {b:?} overflows"))
+}
+
+fn process(input: &[Point]) -> Result<i64> {
+    let mut areas: Vec<i64> = input
+        .iter()
+        .tuple_combinations()
+        .map(|elem: (&Point, &Point)| area(elem.0, elem.1))
+        .collect::<Result<_>>()?;
+
+    areas.sort();
+    debug!(?areas);
+    Ok(*areas.iter().next_back().unwrap())
+}
+
+fn process2(input: &[Point]) -> Result<i64> {
+    let mut rectangles: Vec<(i64, (&Point, &Point))> = input
+        .iter()
+        .tuple_combinations()
+        .map(|rect: (&Point, &Point)| Ok((area(rect.0, rect.1)?, rect)))
+        .collect::<Result<_>>()?;
+
+    rectangles.sort_by_key(|(area, _)| *area);
+    rectangles.reverse();
+
+    for (area, rect) in rectangles {
+        debug!(?rect);
+        let mut bad = false;
+        for (a, b) in input.iter().chain(input.first()).tuple_windows() {
+            // debug!(?a, ?b);
+
+            // intersection test
+            if min(a.x, b.x) < max(rect.0.x, rect.1.x)
+                && min(a.y, b.y) < max(rect.0.y, rect.1.y)
+                && max(a.x, b.x) > min(rect.0.x, rect.1.x)
+                && max(a.y, b.y) > min(rect.0.y, rect.1.y)
+            {
+                debug!("intersection...");
+                bad = true;
+                break;
+            }
+        }
+        if bad {
+            continue;
+        } else {
+            debug!(?rect, "Found area: {}", area);
+            return Ok(area);
+        }
+    }
+
+    Ok(0)
+}
+
+//   0 1 2 3 4 5 6 7 8 9 0 1 2 3
+// 0 . . . . . . . . . . . . . .
+// 1 . . . . . . . # . . . # . .
+// 2 . . . . . . . . . . . . . .
+// 3 . . # . . . . # . . . . . .
+// 4 . . . . . . . . . . . . . .
+// 5 . . # . . . . . . # . . . .
+// 6 . . . . . . . . . . . . . .
+// 7 . . . . . . . . . # . # . .
+// 8 . . . . . . . . . . . . . .
+//
+//   0 1 2 3 4 5 6 7 8 9 0 1 2 3
+// 0 . . . . . . . . . . . . . .
+// 1 . . . . . . . # X X X # . .
+// 2 . . . . . . . X . . . X . .
+// 3 . . # X X X X # . . . X . .
+// 4 . . X . . . . . . . . X . .
+// 5 . . # X X X X X X # . X . .
+// 6 . . . . . . . . . X . X . .
+// 7 . . . . . . . . . # X # . .
+// 8 . . . . . . . . . . . . . .
+
+impl Solution for Day09 {
+    type Input = Vec<Point>;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Movie Theater";
+
+    fn parse(&self, input: &str) -> Result<Vec<Point>> {
+        parse(input)
+    }
+    fn part1(&self, input: &Vec<Point>) -> Result<Answer> {
+        let res = process(input)?;
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Vec<Point>) -> Result<Answer> {
+        let res = process2(input)?;
+        Ok(res.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2025::day09::*;
+
+    #[test_log::test]
+    fn test_parse() {
+        assert!(parse("7,1\n11,1\n").unwrap().len() == 2);
+        assert!(parse("7,1\n").is_err());
+        assert!(parse("7;1\n11,1\n").is_err());
+        assert!(process(&parse("0,0\n4294967296,4294967296\n").unwrap()).is_err());
+    }
+}
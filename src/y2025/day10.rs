@@ -0,0 +1,216 @@
+use std::{collections::HashMap, fmt::Display};
+
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::{Answer, Solution};
+
+#[derive(Debug, Default)]
+pub struct Day10;
+
+/// Machine, with its indicator lights, button wiring and joltage
+/// requirements.
+#[derive(Debug, Default)]
+pub struct Machine {
+    target_len: i32,
+    target: u16,
+    switches: Vec<u16>,
+    joltages: Vec<i32>,
+}
+
+/// Presses of the switches toggling each pattern of indicators.
+type Cache = HashMap<u16, Vec<Vec<u16>>>;
+
+impl TryFrom<&str> for Machine {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut target = 0;
+        let mut target_len = 0;
+        let mut switches = vec![];
+        let mut joltages = vec![];
+        for s in value.split_whitespace() {
+            let inner = || {
+                s.get(1..s.len() - 1)
+                    .with_context(|| format!("unterminated '{s}'"))
+            };
+            match s.chars().next().unwrap() {
+                '[' => {
+                    let s = inner()?;
+                    target_len = s.len();
+                    if target_len > 16 {
+                        bail!("{target_len} indicator lights, at most 16 supported");
+                    }
+                    for (i, c) in s.char_indices() {
+                        match c {
+                            '#' => target |= 1 << i,
+                            '.' => {}
+                            _ => bail!("invalid indicator light '{c}'"),
+                        }
+                    }
+                }
+                '(' => {
+                    let mut sw = 0_u16;
+                    for n in inner()?.split(',') {
+                        let bit = n
+                            .parse::<u16>()
+                            .with_context(|| format!("invalid button wiring '{s}'"))?;
+                        if bit >= 16 {
+                            bail!("button wired to light {bit}, at most 16 supported");
+                        }
+                        sw |= 1 << bit;
+                    }
+                    switches.push(sw);
+                }
+                '{' => {
+                    joltages = inner()?
+                        .split(',')
+                        .map(|x| {
+                            x.parse::<i32>()
+                                .with_context(|| format!("invalid joltages '{s}'"))
+                        })
+                        .collect::<Result<_>>()?;
+                }
+                _ => bail!("invalid machine '{value}'"),
+            }
+        }
+        if joltages.len() > 16 {
+            bail!("{} joltage counters, at most 16 supported", joltages.len());
+        }
+
+        Ok(Machine {
+            target_len: target_len as i32,
+            target,
+            switches,
+            joltages,
+        })
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Machine>> {
+    input.lines().map(Machine::try_from).collect()
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "len={} target={:#b}\t", self.target_len, self.target)?;
+        for s in &self.switches {
+            write!(f, " {:#b}\t", s)?;
+        }
+        write!(f, "joltages={:?}", self.joltages)
+    }
+}
+
+fn get_presses(machine: &Machine, target: u16) -> Vec<Vec<u16>> {
+    debug!(?target, "get_presses");
+    let mut presses = vec![];
+    for i in 0..machine.switches.len() + 1 {
+        for switches_combination in machine.switches.iter().combinations(i) {
+            if switches_combination.iter().fold(0, |acc, &x| acc ^ x) == target {
+                presses.push(switches_combination.iter().map(|&x| *x).collect());
+            }
+        }
+    }
+    presses
+}
+
+fn configure_indicators(machine: &Machine) -> Result<i32> {
+    let presses = get_presses(machine, machine.target);
+    let fewest = presses
+        .iter()
+        .min_by_key(|x| x.len())
+        .with_context(|| format!("no way to configure the indicators of {machine}"))?;
+    Ok(fewest.len() as i32)
+}
+
+fn process(input: &[Machine]) -> Result<i32> {
+    input
+        .iter()
+        .try_fold(0, |acc, machine| Ok(acc + configure_indicators(machine)?))
+}
+
+fn configure_joltages(machine: &Machine, cache: &mut Cache, joltages: Vec<i32>) -> Option<i32> {
+    debug!(?joltages, "Target joltages");
+
+    // target joltages are all 0, we are done
+    if joltages.iter().all(|&x| x == 0) {
+        return Some(0);
+    }
+
+    let indicators: Vec<u16> = joltages.iter().map(|x| (x % 2) as u16).collect();
+    let mut target = 0;
+    for (pos, n) in indicators.iter().enumerate() {
+        target |= n << pos;
+    }
+
+    let patterns = match cache.get(&target) {
+        Some(p) => p.clone(),
+        None => {
+            let p = get_presses(machine, target);
+            cache.insert(target, p.clone());
+            p
+        }
+    };
+    debug!(?patterns, "Patterns");
+
+    let mut result: Option<i32> = None;
+    for presses in &patterns {
+        debug!(?presses, "Applying presses:");
+        // Apply presses to find new target joltages
+        let mut new_joltages = joltages.clone();
+        for p in presses {
+            for (idx, item) in new_joltages.iter_mut().enumerate() {
+                if p & (1 << idx) != 0 {
+                    *item -= 1;
+                }
+            }
+        }
+
+        if new_joltages.iter().any(|&x| x < 0) {
+            continue;
+        }
+
+        // all joltages are even
+        let new_joltages: Vec<i32> = new_joltages.iter().map(|&x| x / 2).collect();
+        if let Some(half_target_presses) = configure_joltages(machine, cache, new_joltages) {
+            let num_presses = presses.len() as i32 + 2 * half_target_presses;
+            result = match result {
+                Some(res) => Some(num_presses.min(res)),
+                None => Some(num_presses),
+            };
+        } else {
+            continue;
+        }
+    }
+
+    result
+}
+
+fn process2(input: &[Machine]) -> Result<i32> {
+    input.iter().try_fold(0, |acc, machine| {
+        debug!("Processing machine: {}", machine);
+        let joltages = machine.joltages.clone();
+        let presses = configure_joltages(machine, &mut Cache::new(), joltages)
+            .with_context(|| format!("no way to configure the joltages of {machine}"))?;
+        Ok(acc + presses)
+    })
+}
+
+impl Solution for Day10 {
+    type Input = Vec<Machine>;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Factory";
+
+    fn parse(&self, input: &str) -> Result<Vec<Machine>> {
+        parse(input)
+    }
+    fn part1(&self, input: &Vec<Machine>) -> Result<Answer> {
+        let res = process(input)?;
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Vec<Machine>) -> Result<Answer> {
+        let res = process2(input)?;
+        Ok(res.into())
+    }
+}
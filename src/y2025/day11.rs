@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result, bail};
+use tracing::debug;
+
+use crate::{Answer, Solution};
+
+#[derive(Debug, Default)]
+pub struct Day11;
+
+/// Devices, along with the devices their outputs are connected to.
+#[derive(Debug)]
+pub struct Graph {
+    map: HashMap<String, Vec<String>>,
+}
+
+/// Number of paths from a device to the current target.
+type Cache = HashMap<String, i64>;
+
+fn parse(input: &str) -> Result<Graph> {
+    let mut map: HashMap<String, Vec<String>> = HashMap::new();
+    for line in input.lines() {
+        let Some((node, neighbours)) = line.split_once(':') else {
+            bail!("invalid device '{line}'");
+        };
+        let neighbours = neighbours.split_whitespace().map(String::from).collect();
+        map.insert(node.into(), neighbours);
+    }
+    Ok(Graph { map })
+}
+
+fn walk(
+    graph: &Graph,
+    cache: &mut Cache,
+    node: &str,
+    mut visited: Vec<String>,
+    target: &str,
+) -> Result<i64> {
+    let mut total = 0;
+    visited.push(node.into());
+    debug!(?node, ?visited, ?target, "Entering walk2");
+
+    if node == target {
+        return Ok(1);
+    }
+
+    if node == "out" {
+        return Ok(0);
+    }
+
+    if let Some((_, &v)) = cache.get_key_value(node) {
+        return Ok(v);
+    }
+
+    // devices without outputs lead nowhere
+    for neighbour in graph.map.get(node).into_iter().flatten() {
+        if visited.contains(neighbour) {
+            continue;
+        }
+        total = walk(graph, cache, neighbour, visited.clone(), target)?
+            .checked_add(total)
+            .context("the number of paths overflows")?;
+    }
+
+    cache.insert(node.into(), total);
+
+    Ok(total)
+}
+
+/// Number of paths from device `from` to device `to`.
+fn paths(graph: &Graph, from: &str, to: &str) -> Result<i64> {
+    if !graph.map.contains_key(from) {
+        bail!("no device {from}");
+    }
+    walk(graph, &mut Cache::new(), from, vec![], to)
+}
+
+fn process(input: &Graph) -> Result<i64> {
+    paths(input, "you", "out")
+}
+
+fn process2(input: &Graph) -> Result<i64> {
+    let a = paths(input, "svr", "fft")?;
+    let b = paths(input, "fft", "dac")?;
+    let c = paths(input, "dac", "out")?;
+    a.checked_mul(b)
+        .and_then(|ab| ab.checked_mul(c))
+        .context("the number of paths overflows")
+}
+
+impl Solution for Day11 {
+    type Input = Graph;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Reactor";
+    const COMMANDS: &'static [(&'static str, &'static str)] =
+        &[("paths FROM TO", "number of paths from device FROM to device TO")];
+
+    fn parse(&self, input: &str) -> Result<Graph> {
+        parse(input)
+    }
+    fn part1(&self, input: &Graph) -> Result<Answer> {
+        let res = process(input)?;
+        Ok(res.into())
+    }
+    fn part2(&self, input: &Graph) -> Result<Answer> {
+        let res = process2(input)?;
+        Ok(res.into())
+    }
+    fn command(&self, input: &Graph, name: &str, args: &[&str]) -> Result<Answer> {
+        match (name, args) {
+            ("paths", [from, to]) => Ok(paths(input, from, to)?.into()),
+            _ => bail!("usage: paths FROM TO"),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::vec;
+
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+
+use crate::{Answer, Solution};
+
+#[derive(Debug, Default)]
+pub struct Day12;
+
+#[derive(Clone, Debug, Default)]
+struct Present {
+    shape: Vec<Vec<bool>>,
+}
+
+#[derive(Debug, Default)]
+struct Region {
+    length: usize,
+    width: usize,
+    nshapes: Vec<u32>,
+}
+
+impl Present {
+    fn new() -> Self {
+        Present {
+            shape: vec![vec![], vec![], vec![]],
+        }
+    }
+
+    #[allow(unused)]
+    fn area(&self) -> i32 {
+        let mut area = 0;
+        for line in &self.shape {
+            area += line.iter().fold(0, |acc, &x| if x { acc + 1 } else { acc });
+        }
+        area
+    }
+}
+
+impl Region {
+    /// Area of the region, in u128 to fit the product of any two sizes.
+    fn area(&self) -> u128 {
+        self.length as u128 * self.width as u128
+    }
+}
+
+impl TryFrom<&str> for Region {
+    type Error = anyhow::Error;
+
+    fn try_from(line: &str) -> Result<Self> {
+        let (size, nshapes) = line
+            .split_once(':')
+            .with_context(|| format!("invalid region '{line}'"))?;
+        let (length, width) = size
+            .split('x')
+            .map(|x| x.parse::<usize>())
+            .collect_tuple()
+            .and_then(|(length, width)| Some((length.ok()?, width.ok()?)))
+            .with_context(|| format!("invalid size in region '{line}'"))?;
+
+        let nshapes = nshapes
+            .split_whitespace()
+            .map(|x| {
+                x.parse::<u32>()
+                    .with_context(|| format!("invalid quantity in region '{line}'"))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Region {
+            length,
+            width,
+            nshapes,
+        })
+    }
+}
+
+/// Shapes of the presents, then the regions under the trees to fit them in.
+#[derive(Debug)]
+pub struct Farm {
+    #[allow(unused)]
+    presents: Vec<Present>,
+    regions: Vec<Region>,
+}
+
+fn parse(input: &str) -> Result<Farm> {
+    let mut presents: Vec<Present> = vec![];
+    let mut present: Option<Present> = None;
+    let mut shape_idx = 0;
+    let mut regions: Vec<Region> = vec![];
+
+    for line in input.lines() {
+        match line {
+            _ if line.contains(':') && !line.contains('x') => {
+                // new present
+                shape_idx = 0;
+                present = Some(Present::new());
+            }
+            _ if line.starts_with('.') || line.starts_with('#') => {
+                // fill present shape
+                let shape = present
+                    .as_mut()
+                    .and_then(|present| present.shape.get_mut(shape_idx))
+                    .with_context(|| format!("unexpected shape line '{line}'"))?;
+                for c in line.chars() {
+                    match c {
+                        '.' => shape.push(false),
+                        '#' => shape.push(true),
+                        _ => bail!("invalid shape line '{line}'"),
+                    }
+                }
+                shape_idx += 1;
+            }
+            _ if line.is_empty() => {
+                // finalize present
+                presents.extend(present.take());
+            }
+            _ if line.contains(':') && line.contains('x') => {
+                // region
+                regions.push(line.try_into()?);
+            }
+            _ => bail!("unexpected line '{line}'"),
+        }
+    }
+    presents.extend(present);
+
+    Ok(Farm { presents, regions })
+}
+
+fn process(input: &Farm) -> i32 {
+    let mut total = 0;
+    for region in &input.regions {
+        let sum = region.nshapes.iter().map(|&n| u128::from(n)).sum::<u128>() * 8;
+        if sum < region.area() {
+            total += 1;
+            continue;
+        }
+    }
+
+    total
+}
+
+impl Solution for Day12 {
+    type Input = Farm;
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Christmas Tree Farm";
+    const PARTS: &'static [u8] = &[1];
+
+    fn parse(&self, input: &str) -> Result<Farm> {
+        parse(input)
+    }
+    fn part1(&self, input: &Farm) -> Result<Answer> {
+        let res = process(input);
+        Ok(res.into())
+    }
+}
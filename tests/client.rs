@@ -0,0 +1,167 @@
+//! Fetches inputs and submits answers against a local server mimicking
+//! the Advent of Code one.
+
+use std::{
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use adventofcode2025::{
+    Year,
+    client::{self, Client, Outcome, Submissions},
+};
+
+/// Method, path and body of the requests received, with the cookie sent.
+type Requests = Arc<Mutex<Vec<(String, String, String)>>>;
+
+/// Start a server answering requests with `respond(method, path, body)`,
+/// which returns a status and a page. Returns its URL and the requests
+/// received.
+fn serve(respond: fn(&str, &str, &str) -> (u16, String)) -> (String, Requests) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Requests::default();
+    let received = requests.clone();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut words = line.split_whitespace();
+            let (method, path) = (words.next().unwrap(), words.next().unwrap());
+            let (mut length, mut cookie) = (0, String::new());
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let Some((name, value)) = header.trim_end().split_once(": ") else {
+                    break;
+                };
+                match name.to_lowercase().as_str() {
+                    "content-length" => length = value.parse().unwrap(),
+                    "cookie" => cookie = value.to_string(),
+                    _ => {}
+                }
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let body = String::from_utf8(body).unwrap();
+            let (status, page) = respond(method, path, &body);
+            received
+                .lock()
+                .unwrap()
+                .push((format!("{method} {path}"), body, cookie));
+            write!(
+                stream,
+                "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                page.len()
+            )
+            .unwrap();
+        }
+    });
+    (url, requests)
+}
+
+fn page(text: &str) -> String {
+    format!("<html><main><article><p>{text}</p></article></main></html>")
+}
+
+fn respond(method: &str, path: &str, body: &str) -> (u16, String) {
+    match (method, path, body) {
+        ("GET", "/2025/day/1/input", _) => (200, "L68\nR30\n".to_string()),
+        ("GET", _, _) => (404, "Not Found".to_string()),
+        (_, "/2025/day/1/answer", "level=1&answer=42") => (200, page("That's the right answer!")),
+        (_, "/2025/day/1/answer", "level=1&answer=1") => (
+            200,
+            page("That's not the right answer; your answer is too low."),
+        ),
+        _ => (
+            200,
+            page("You gave an answer too recently. You have 2m 5s left to wait."),
+        ),
+    }
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-client-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test_log::test]
+fn test_fetch() {
+    let (url, requests) = serve(respond);
+    let client = Client::new(&url, "token");
+    let dir = temp_dir("fetch");
+
+    let (path, fetched) = client::fetch(&client, Year::Y2025, 1, &dir, false).unwrap();
+    assert!(fetched);
+    assert!(fs::read_to_string(&path).unwrap() == "L68\nR30\n");
+    let (_, fetched) = client::fetch(&client, Year::Y2025, 1, &dir, false).unwrap();
+    assert!(!fetched);
+    let (_, fetched) = client::fetch(&client, Year::Y2025, 1, &dir, true).unwrap();
+    assert!(fetched);
+    let e = client::fetch(&client, Year::Y2025, 25, &dir, false).unwrap_err();
+    assert!(e.to_string().contains("not unlocked"));
+
+    let requests = requests.lock().unwrap();
+    assert!(requests.len() == 3);
+    assert!(requests[0].0 == "GET /2025/day/1/input");
+    assert!(requests[0].2 == "session=token");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test_log::test]
+fn test_submit() {
+    let (url, requests) = serve(respond);
+    let client = Client::new(&url, "token");
+    let dir = temp_dir("submit");
+    let path = dir.join("submissions.json");
+
+    let mut submissions = Submissions::load(&path).unwrap();
+    let outcome = submissions.submit(&client, Year::Y2025, 1, 1, "1").unwrap();
+    assert!(
+        outcome
+            == Outcome::Wrong {
+                hint: Some("too low".into())
+            }
+    );
+    // the server asks to wait a minute after a wrong answer
+    let e = submissions
+        .submit(&client, Year::Y2025, 1, 1, "42")
+        .unwrap_err();
+    assert!(e.to_string().contains("too soon"));
+    // answers already submitted are not sent again
+    let mut submissions = Submissions::load(&path).unwrap();
+    let outcome = submissions.submit(&client, Year::Y2025, 1, 1, "1").unwrap();
+    assert!(matches!(outcome, Outcome::Wrong { .. }));
+    assert!(requests.lock().unwrap().len() == 1);
+    assert!(requests.lock().unwrap()[0].1 == "level=1&answer=1");
+
+    let mut submissions = Submissions::load(&dir.join("other.json")).unwrap();
+    let outcome = submissions
+        .submit(&client, Year::Y2025, 1, 1, "42")
+        .unwrap();
+    assert!(outcome == Outcome::Right);
+    let e = submissions
+        .submit(&client, Year::Y2025, 1, 1, "43")
+        .unwrap_err();
+    assert!(e.to_string().contains("already solved"));
+    let outcome = submissions.submit(&client, Year::Y2025, 1, 2, "7").unwrap();
+    assert!(
+        outcome
+            == Outcome::TooSoon {
+                wait: std::time::Duration::from_secs(125)
+            }
+    );
+    let e = submissions
+        .submit(&client, Year::Y2025, 1, 2, "8")
+        .unwrap_err();
+    assert!(e.to_string().contains("wait 12"));
+    assert!(requests.lock().unwrap().len() == 3);
+    fs::remove_dir_all(&dir).unwrap();
+}
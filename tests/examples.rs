@@ -0,0 +1,73 @@
+//! Runs every fixture of `examples/<year>/dayNN/`, see [`Fixture`].
+
+use std::path::Path;
+
+use adventofcode2025::{
+    RunOptions,
+    answers::Verdict,
+    examples::Fixture,
+    report::{Record, Reporter},
+    selection::DaySelection,
+};
+use anyhow::Result;
+
+#[derive(Default)]
+struct Failures(Vec<String>);
+
+impl Reporter for Failures {
+    fn record(&mut self, record: Record) {
+        let failure = match (&record.error, &record.verdict, record.answer) {
+            (Some(error), _, _) => error.clone(),
+            (None, Some(Verdict::Fail { expected }), Some(answer)) => {
+                format!("answered {answer}, expected {expected}")
+            }
+            _ => return,
+        };
+        self.0.push(format!("part {}: {failure}", record.part));
+    }
+}
+
+fn check(fixture: &Fixture) -> Result<Vec<String>> {
+    let Some(options) = fixture.options(&RunOptions::default())? else {
+        return Ok(vec![]);
+    };
+    let day = fixture.day;
+    let days = DaySelection::parse(day.year(), &day.number().to_string())?;
+    let mut failures = Failures::default();
+    adventofcode2025::run(&days, &options, &mut failures)?;
+    Ok(failures.0)
+}
+
+#[test_log::test]
+fn test_examples() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let fixtures = Fixture::discover(&root.join("examples")).unwrap();
+    assert!(!fixtures.is_empty());
+
+    let mut failures = vec![];
+    for fixture in &fixtures {
+        let name = fixture.input.strip_prefix(root).unwrap_or(&fixture.input);
+        match check(fixture) {
+            Ok(errors) => {
+                failures.extend(errors.iter().map(|e| format!("{}: {e}", name.display())))
+            }
+            Err(e) => failures.push(format!("{}: {e:#}", name.display())),
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test_log::test]
+fn test_embedded() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let fixtures = Fixture::discover(&root.join("examples")).unwrap();
+    let embedded = Fixture::embedded().unwrap();
+    let inputs =
+        |fixtures: &[Fixture]| fixtures.iter().map(|f| f.input.clone()).collect::<Vec<_>>();
+    assert_eq!(inputs(&embedded), inputs(&fixtures));
+
+    for fixture in &embedded {
+        let errors = check(fixture).unwrap();
+        assert!(errors.is_empty(), "{}", errors.join("\n"));
+    }
+}